@@ -1,5 +1,6 @@
-use criterion::{Criterion, black_box};
+use criterion::Criterion;
 use qbin::Cell;
+use std::hint::black_box;
 
 const QBIN: u64 = 5246083350086549503;
 const GEOHASH: &str = "rbsm1hsuvshv";
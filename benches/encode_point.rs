@@ -1,6 +1,7 @@
-use criterion::{Criterion, black_box};
+use criterion::Criterion;
 use h3o::{LatLng, Resolution};
 use qbin::Cell;
+use std::hint::black_box;
 
 const LAT: f64 = -41.28303675124842;
 const LNG: f64 = 174.77727344223067;
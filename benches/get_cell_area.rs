@@ -1,6 +1,7 @@
-use criterion::{BenchmarkId, Criterion, black_box};
+use criterion::{BenchmarkId, Criterion};
 use h3o::CellIndex;
-use qbin::Cell;
+use qbin::{Cell, EarthModel};
+use std::hint::black_box;
 
 pub const HEXAGONS: [u64; 16] = [
     0x801ffffffffffff,
@@ -51,7 +52,7 @@ pub fn bench(c: &mut Criterion) {
         });
     }
 
-    // Benchmark each resolution for quadbin
+    // Benchmark each resolution for quadbin, on the ellipsoid and the sphere
     for (i, &qb_index) in QUADBINS.iter().enumerate() {
         group.bench_with_input(BenchmarkId::new("qbin", i), &qb_index, |b, &index| {
             let cell = Cell::try_from(index).expect("cell index");
@@ -59,5 +60,16 @@ pub fn bench(c: &mut Criterion) {
         });
     }
 
+    for (i, &qb_index) in QUADBINS.iter().enumerate() {
+        group.bench_with_input(
+            BenchmarkId::new("qbin_sphere", i),
+            &qb_index,
+            |b, &index| {
+                let cell = Cell::try_from(index).expect("cell index");
+                b.iter(|| black_box(cell).area_m2_with(EarthModel::Sphere))
+            },
+        );
+    }
+
     group.finish();
 }
@@ -1,6 +1,7 @@
-use criterion::{Criterion, black_box};
+use criterion::Criterion;
 use h3o::CellIndex;
 use qbin::Cell;
+use std::hint::black_box;
 
 const INPUT_H3: u64 = 0x8f734e64992d6d8;
 const INPUT_QB: u64 = 5209574053332910079;
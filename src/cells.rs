@@ -1,9 +1,9 @@
-use crate::Direction;
 use crate::constants::*;
 use crate::errors::QuadbinError;
 use crate::tiles::Tile;
 use crate::utils::*;
-use core::{fmt, num::NonZeroU64};
+use crate::{Adjacency, CellSet, Direction, EarthModel, Topology};
+use core::{fmt, num::NonZeroU64, ops::RangeInclusive, str::FromStr};
 
 /// Represents a cell in the Quadbin grid system at a
 /// particular resolution.
@@ -24,7 +24,18 @@ use core::{fmt, num::NonZeroU64};
 /// - `R`: Cell resolution, ranging from `0` to `26`, encoded in bits 52–56;
 /// - Remaining bits (0–51) encode the cell’s XY position in Morton order (Z-order curve).
 ///
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// Cells are ordered by their `u64` index: at a given resolution, they
+/// follow the Morton order (Z-order curve).
+///
+/// With the `serde` feature, cells are serialized as their `u64` index
+/// and validated on deserialization. See the `serde_cell` module to
+/// serialize them as strings instead.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u64", into = "u64")
+)]
 pub struct Cell(NonZeroU64);
 
 impl TryFrom<u64> for Cell {
@@ -39,6 +50,12 @@ impl TryFrom<u64> for Cell {
     }
 }
 
+impl From<Cell> for u64 {
+    fn from(value: Cell) -> Self {
+        value.get()
+    }
+}
+
 impl Cell {
     /// Returns the inner u64 value of the cell.
     pub fn get(&self) -> u64 {
@@ -145,15 +162,189 @@ impl Cell {
         }))
     }
 
+    /// Return the descendant with the lowest index at a specific resolution.
+    ///
+    /// It is the north-western descendant, as children are numbered in
+    /// Morton order. A resolution equal to the cell's returns the cell
+    /// itself.
+    ///
+    /// See also [Cell::max_child] and [Cell::descendant_range].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is lower than Cell's
+    /// resolution or greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let first = cell.children(5).expect("children").next().expect("child");
+    /// assert_eq!(cell.min_child(5), first);
+    /// ```
+    pub fn min_child(&self, res: u8) -> Result<Self, QuadbinError> {
+        let (min, _) = cell_descendant_bounds(self, res)?;
+        Cell::try_from(min)
+    }
+
+    /// Return the descendant with the highest index at a specific
+    /// resolution.
+    ///
+    /// It is the south-eastern descendant, see [Cell::min_child].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is lower than Cell's
+    /// resolution or greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let last = cell.children(5).expect("children").last().expect("child");
+    /// assert_eq!(cell.max_child(5), last);
+    /// ```
+    pub fn max_child(&self, res: u8) -> Result<Self, QuadbinError> {
+        let (_, max) = cell_descendant_bounds(self, res)?;
+        Cell::try_from(max)
+    }
+
+    /// Return the range of indexes covering all descendants at a specific
+    /// resolution.
+    ///
+    /// The resolution bits sit above the Morton bits, hence descendants of
+    /// a cell at a given resolution are contiguous in index order, and the
+    /// range contains no other cell of any resolution. A sorted column of
+    /// cells can therefore be filtered to everything under an ancestor
+    /// with a single range scan.
+    ///
+    /// Not every integer in the range is a valid cell index.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is lower than Cell's
+    /// resolution or greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let range = cell.descendant_range(10).expect("range");
+    ///
+    /// let inside = Cell::from_point(-11.17, 33.75, 10).expect("cell index");
+    /// let outside = Cell::from_point(-11.17, 60.0, 10).expect("cell index");
+    /// assert!(range.contains(&inside.get()));
+    /// assert!(!range.contains(&outside.get()));
+    /// ```
+    pub fn descendant_range(&self, res: u8) -> Result<RangeInclusive<u64>, QuadbinError> {
+        let (min, max) = cell_descendant_bounds(self, res)?;
+        Ok(min..=max)
+    }
+
+    /// Find all descendants at a specific resolution in a sorted slice of
+    /// cells.
+    ///
+    /// The slice must be sorted in ascending order and may mix
+    /// resolutions. The lookup is a binary search, see
+    /// [Cell::descendant_range].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is lower than Cell's
+    /// resolution or greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let mut cells = vec![
+    ///     Cell::from_point(-11.17, 33.75, 10).expect("cell index"),
+    ///     Cell::from_point(-11.17, 60.0, 10).expect("cell index"),
+    ///     Cell::from_point(-11.17, 33.75, 12).expect("cell index"),
+    /// ];
+    /// cells.sort();
+    ///
+    /// let found = cell.descendants_in(&cells, 10).expect("descendants");
+    /// assert_eq!(found, &[Cell::from_point(-11.17, 33.75, 10).expect("cell index")]);
+    /// ```
+    pub fn descendants_in<'a>(
+        &self,
+        cells: &'a [Cell],
+        res: u8,
+    ) -> Result<&'a [Cell], QuadbinError> {
+        let (min, max) = cell_descendant_bounds(self, res)?;
+        let start = cells.partition_point(|cell| cell.get() < min);
+        let end = start + cells[start..].partition_point(|cell| cell.get() <= max);
+
+        Ok(&cells[start..end])
+    }
+
+    /// Compact a set of cells, replacing every complete group of four
+    /// siblings with their parent, recursively.
+    ///
+    /// Cells may be of mixed resolutions. The output is sorted in
+    /// ascending index order.
+    ///
+    /// See also [Cell::uncompact].
+    ///
+    /// # Errors
+    /// [QuadbinError::OverlappingCells] if the input contains duplicates,
+    /// or a cell together with one of its ancestors.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let parent = Cell::new(5209574053332910079);
+    /// let kids = parent
+    ///     .children(6)
+    ///     .expect("valid children")
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .expect("cell index");
+    ///
+    /// assert_eq!(Cell::compact(kids), Ok(vec![parent]));
+    /// ```
+    pub fn compact(cells: impl IntoIterator<Item = Cell>) -> Result<Vec<Self>, QuadbinError> {
+        compact_cells(cells)
+    }
+
+    /// Expand a set of cells to a uniform resolution.
+    ///
+    /// Cells already at the requested resolution are kept as-is, coarser
+    /// ones are replaced by their children.
+    ///
+    /// See also [Cell::compact].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26 or
+    /// lower than the resolution of any input cell.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cells = Cell::uncompact([Cell::new(5209574053332910079)], 5).expect("cells");
+    /// assert_eq!(cells.len(), 4);
+    /// ```
+    pub fn uncompact(
+        cells: impl IntoIterator<Item = Cell>,
+        res: u8,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        uncompact_cells(cells, res)
+    }
+
     /// Find the Cell's neighbor in a specific [Direction].
     ///
     /// In the original JavaScript implementation, this operation is called
     /// sibling. However, following the H3 naming convention, we decided
     /// to name sibling's as neighbors.
     ///
-    /// See [Direction] for allowed arguments.
+    /// See [Direction] for allowed arguments, [Direction::Center] returns
+    /// the cell itself.
     ///
-    /// Return `None` if there is no neighbor in this [Direction].
+    /// Return `None` if there is no neighbor in this [Direction]. The grid
+    /// is bounded on all sides, see [Cell::neighbor_with] to wrap across
+    /// the antimeridian.
     ///
     /// # Example
     /// ```
@@ -168,6 +359,31 @@ impl Cell {
         tile.to_cell().ok()
     }
 
+    /// Find the Cell's neighbor in a specific [Direction], using a specific
+    /// [Topology].
+    ///
+    /// With [Topology::Cylindrical], cells in the first and last columns
+    /// are neighbors across the ±180° meridian. There are never neighbors
+    /// beyond the northern and southern edges, nor at resolution 0.
+    ///
+    /// See [Cell::neighbor].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Direction, Topology};
+    ///
+    /// // Fiji, right next to the antimeridian
+    /// let cell = Cell::from_point(-17.7, 179.9, 10).expect("cell index");
+    /// assert_eq!(cell.neighbor_with(Direction::Right, Topology::Bounded), None);
+    ///
+    /// let east = cell.neighbor_with(Direction::Right, Topology::Cylindrical);
+    /// assert_eq!(east, Cell::from_point(-17.7, -179.9, 10).ok());
+    /// ```
+    pub fn neighbor_with(&self, direction: Direction, topology: Topology) -> Option<Self> {
+        let tile = self.to_tile().neighbor_with(direction, topology)?;
+        tile.to_cell().ok()
+    }
+
     /// Find the Cell's sibling in a specific [Direction].
     ///
     /// See [Cell::neighbor].
@@ -175,22 +391,110 @@ impl Cell {
         self.neighbor(direction)
     }
 
-    /// List all Cell's neighbors.
+    /// List all Cell's neighbors sharing an edge.
+    ///
+    /// Neighbors are ordered as [Direction::iter].
     pub fn neighbors(&self) -> [Option<Cell>; 4] {
+        self.neighbors_with(Topology::Bounded)
+    }
+
+    /// List all Cell's neighbors sharing an edge, using a specific
+    /// [Topology].
+    ///
+    /// See [Cell::neighbors] and [Cell::neighbor_with].
+    pub fn neighbors_with(&self, topology: Topology) -> [Option<Cell>; 4] {
         let mut neighbors = [None; 4];
 
         for (i, neighbor) in neighbors.iter_mut().enumerate() {
-            *neighbor = self.neighbor(Direction::new_unchecked(i as u8));
+            *neighbor = self.neighbor_with(Direction::new_unchecked(i as u8), topology);
         }
 
         neighbors
     }
 
-    // TODO:
-    // Add `direction_to_neighbor` -- return Direction to neighbor
+    /// List all Cell's neighbors sharing an edge or a corner (Moore
+    /// neighborhood).
+    ///
+    /// Neighbors are ordered as [Direction::iter_neighborhood] with
+    /// [Neighborhood::Moore](crate::Neighborhood::Moore), the first four
+    /// being identical to [Cell::neighbors].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Direction};
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let neighbors = cell.neighbors8();
+    /// assert_eq!(neighbors[4], cell.neighbor(Direction::UpRight));
+    /// assert!(neighbors.iter().all(|n| n.is_some()));
+    /// ```
+    pub fn neighbors8(&self) -> [Option<Cell>; 8] {
+        self.neighbors8_with(Topology::Bounded)
+    }
+
+    /// List all Cell's neighbors sharing an edge or a corner, using a
+    /// specific [Topology].
+    ///
+    /// See [Cell::neighbors8] and [Cell::neighbor_with].
+    pub fn neighbors8_with(&self, topology: Topology) -> [Option<Cell>; 8] {
+        let mut neighbors = [None; 8];
+
+        for (i, neighbor) in neighbors.iter_mut().enumerate() {
+            *neighbor = self.neighbor_with(Direction::new_unchecked(i as u8), topology);
+        }
+
+        neighbors
+    }
+
+    /// Find the [Direction] from this cell to a neighboring cell.
+    ///
+    /// Both cells must share the same resolution. Diagonal neighbors
+    /// are supported.
+    ///
+    /// Return `None` if cells are not adjacent, identical or at different
+    /// resolutions, see [Cell::adjacency] for the latter.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Direction};
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let sibling = Cell::new(5209626829891043327);
+    /// assert_eq!(cell.direction_to_neighbor(&sibling), Some(Direction::Right));
+    /// assert_eq!(sibling.direction_to_neighbor(&cell), Some(Direction::Left));
+    /// ```
+    pub fn direction_to_neighbor(&self, other: &Cell) -> Option<Direction> {
+        cell_direction_to_neighbor(self, other)
+    }
+
+    /// Classify how this cell touches another one.
+    ///
+    /// Cells may be of different resolutions. Overlapping cells, i.e.
+    /// identical cells or a cell and one of its descendants, are reported
+    /// as [Adjacency::NotAdjacent].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Adjacency, Cell, Direction};
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let corner = cell.neighbor(Direction::DownLeft).expect("cell index");
+    /// assert_eq!(cell.adjacency(&corner), Adjacency::SharesCorner);
+    ///
+    /// // A smaller cell along the eastern edge
+    /// let right = cell.neighbor(Direction::Right).expect("cell index");
+    /// let kid = right.children(6).expect("children").next().expect("child").expect("cell index");
+    /// assert_eq!(cell.adjacency(&kid), Adjacency::SharesEdge);
+    /// ```
+    pub fn adjacency(&self, other: &Cell) -> Adjacency {
+        cell_adjacency(self, other)
+    }
 
     /// Computes the area of this Quadbin cell, in m².
     ///
+    /// The area is exact on the WGS84 ellipsoid, see [Cell::area_m2_with]
+    /// for other Earth models.
+    ///
     /// See also [Cell::area_km2].
     ///
     /// # Example
@@ -200,11 +504,31 @@ impl Cell {
     ///
     /// let my_cell = Cell::try_from(5234261499580514303_u64).expect("cell index");
     /// let area = my_cell.area_m2();
-    /// assert_relative_eq!(area, 888546364.7859862, epsilon = 1e-6)
+    /// assert_relative_eq!(area, 889552613.7494099, epsilon = 1e-6)
     ///
     /// ```
     pub fn area_m2(&self) -> f64 {
-        self.to_tile().area()
+        self.area_m2_with(EarthModel::Wgs84)
+    }
+
+    /// Computes the area of this Quadbin cell on a specific [EarthModel],
+    /// in m².
+    ///
+    /// Cells are bounded by meridians and parallels, so their area has a
+    /// closed-form expression on both the sphere and the ellipsoid.
+    ///
+    /// # Example
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use qbin::{Cell, EarthModel};
+    ///
+    /// let my_cell = Cell::try_from(5234261499580514303_u64).expect("cell index");
+    /// let area = my_cell.area_m2_with(EarthModel::Sphere);
+    /// assert_relative_eq!(area, 888546291.2459911, epsilon = 1e-6)
+    ///
+    /// ```
+    pub fn area_m2_with(&self, model: EarthModel) -> f64 {
+        self.to_tile().area(model)
     }
 
     /// Computes the area of this Quadbin cell, in km².
@@ -218,13 +542,125 @@ impl Cell {
     ///
     /// let my_cell = Cell::try_from(5234261499580514303_u64).expect("cell index");
     /// let area = my_cell.area_km2();
-    /// assert_relative_eq!(area, 888.5463647859862, epsilon = 1e-6)
+    /// assert_relative_eq!(area, 889.5526137494099, epsilon = 1e-6)
     ///
     /// ```
     pub fn area_km2(&self) -> f64 {
         self.area_m2() / 1_000_000_f64
     }
 
+    /// Computes the length of one of the cell's edges, in meters.
+    ///
+    /// [Direction::Up] and [Direction::Down] select the northern and
+    /// southern edges, measured along their parallel. In Mercator, the edge
+    /// closer to the pole is shorter. [Direction::Left] and
+    /// [Direction::Right] select the western and eastern edges, measured
+    /// along their meridian, and always have the same length.
+    ///
+    /// Lengths are computed on the WGS84 ellipsoid, see
+    /// [Cell::edge_length_m_with] for other Earth models.
+    ///
+    /// Return `None` for diagonal directions and [Direction::Center].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Direction};
+    ///
+    /// let cell = Cell::new(5234261499580514303);
+    /// let north = cell.edge_length_m(Direction::Up).expect("edge");
+    /// let south = cell.edge_length_m(Direction::Down).expect("edge");
+    /// assert!(north < south);
+    /// assert_eq!(cell.edge_length_m(Direction::UpLeft), None);
+    /// ```
+    pub fn edge_length_m(&self, direction: Direction) -> Option<f64> {
+        self.edge_length_m_with(direction, EarthModel::Wgs84)
+    }
+
+    /// Computes the length of one of the cell's edges on a specific
+    /// [EarthModel], in meters.
+    ///
+    /// See [Cell::edge_length_m].
+    pub fn edge_length_m_with(&self, direction: Direction, model: EarthModel) -> Option<f64> {
+        tile_edge_length(&self.to_tile(), direction, model)
+    }
+
+    /// Computes the perimeter of this Quadbin cell, in meters.
+    ///
+    /// The perimeter is the sum of the four edges, see
+    /// [Cell::edge_length_m].
+    ///
+    /// # Example
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::from_point(0.0, 0.0, 26).expect("cell index");
+    /// assert_relative_eq!(cell.perimeter_m(), 2.38, epsilon = 1e-2);
+    /// ```
+    pub fn perimeter_m(&self) -> f64 {
+        self.perimeter_m_with(EarthModel::Wgs84)
+    }
+
+    /// Computes the perimeter of this Quadbin cell on a specific
+    /// [EarthModel], in meters.
+    ///
+    /// See [Cell::perimeter_m].
+    pub fn perimeter_m_with(&self, model: EarthModel) -> f64 {
+        Direction::iter()
+            .filter_map(|direction| self.edge_length_m_with(direction, model))
+            .sum()
+    }
+
+    /// Computes the width and height of this Quadbin cell, in meters.
+    ///
+    /// The width is measured along the parallel through the cell's center
+    /// (see [Cell::to_point]), the height along a meridian.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5234261499580514303);
+    /// let (width, height) = cell.width_height_m();
+    /// assert!(width > 29_000.0 && width < 31_000.0);
+    /// assert!(height > 29_000.0 && height < 31_000.0);
+    /// ```
+    pub fn width_height_m(&self) -> (f64, f64) {
+        self.width_height_m_with(EarthModel::Wgs84)
+    }
+
+    /// Computes the width and height of this Quadbin cell on a specific
+    /// [EarthModel], in meters.
+    ///
+    /// See [Cell::width_height_m].
+    pub fn width_height_m_with(&self, model: EarthModel) -> (f64, f64) {
+        let tile = self.to_tile();
+        let [lat, _] = self.to_point();
+        let span = 2.0 * std::f64::consts::PI / (1_u64 << tile.z) as f64;
+
+        let width = model.parallel_length(lat.to_radians(), span);
+        let height = tile_edge_length(&tile, Direction::Left, model).expect("edge");
+
+        (width, height)
+    }
+
+    /// Convert a Quadbin cell into its lowercase hexadecimal representation,
+    /// without prefix.
+    ///
+    /// Parse it back with [str::parse], see [Cell::from_str].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// assert_eq!(cell.to_hex(), "484c1fffffffffff");
+    /// assert_eq!(format!("{:#X}", cell), "0x484C1FFFFFFFFFFF");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("{:x}", self.get())
+    }
+
     /// Convert a Quadbin cell into geographic point.
     ///
     /// Returns a tuple with latitude and longitude in degrees.
@@ -280,6 +716,298 @@ impl Cell {
         point_to_cell(lat, lng, res)
     }
 
+    /// Convert a geographic point into a Quadbin cell, along with the
+    /// point's position within the cell.
+    ///
+    /// Returns a tuple with the cell and the offsets `fx` and `fy`, in
+    /// `[0, 1)`, measured from the cell's western and northern edges in
+    /// Web Mercator. Coordinates are clipped as in [Cell::from_point].
+    ///
+    /// See [Cell::point_at_offset] for the inverse.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let (cell, fx, fy) = Cell::locate_point(-11.178401873711776, 33.75, 4).expect("cell index");
+    /// assert_eq!(cell, Cell::new(5209574053332910079));
+    /// assert_eq!((fx, fy), (0.5, 0.5));
+    /// ```
+    pub fn locate_point(lat: f64, lng: f64, res: u8) -> Result<(Self, f64, f64), QuadbinError> {
+        let (x, y, z) = point_to_tile_fraction(clip_latitude(lat), clip_longitude(lng), res)?;
+        let cell = Tile::new(x.floor() as u32, y.floor() as u32, z).to_cell()?;
+
+        Ok((cell, x.fract(), y.fract()))
+    }
+
+    /// Convert a position within a Quadbin cell into a geographic point.
+    ///
+    /// Offsets are measured from the cell's western and northern edges in
+    /// Web Mercator, so that `(0.5, 0.5)` is [Cell::to_point] and
+    /// `(1.0, 1.0)` the south-eastern corner. Returns an array with
+    /// latitude and longitude in degrees.
+    ///
+    /// See [Cell::locate_point] for the inverse.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidOffset] if an offset is outside `[0, 1]`.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// assert_eq!(cell.point_at_offset(0.5, 0.5), Ok(cell.to_point()));
+    /// assert_eq!(cell.point_at_offset(0.0, 1.0), Ok([-21.943045533438166, 22.5]));
+    /// ```
+    pub fn point_at_offset(&self, fx: f64, fy: f64) -> Result<[f64; 2], QuadbinError> {
+        tile_to_point(&self.to_tile(), fx, fy)
+    }
+
+    /// List all cells intersecting a bounding box at a specific resolution.
+    ///
+    /// This is the inverse of [Cell::to_bbox]. Bounds are in degrees, and
+    /// boxes with `xmin > xmax` cross the antimeridian. Cells only touching
+    /// the box along an edge are left out, unless the box is a line or a
+    /// point. Latitudes are clipped as in [Cell::from_point].
+    ///
+    /// Cells are yielded lazily, row by row from north to south, and from
+    /// west to east within a row. See [Cell::bbox_ranges] for contiguous
+    /// index ranges instead.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+    /// let cells = Cell::from_bbox(xmin, ymin, xmax, ymax, 4).expect("cells");
+    /// assert_eq!(cells.collect::<Vec<_>>(), vec![cell]);
+    ///
+    /// // Across the antimeridian
+    /// let cells = Cell::from_bbox(170.0, -20.0, -170.0, -10.0, 5).expect("cells");
+    /// assert_eq!(cells.count(), 4);
+    /// ```
+    pub fn from_bbox(
+        xmin: f64,
+        ymin: f64,
+        xmax: f64,
+        ymax: f64,
+        res: u8,
+    ) -> Result<impl Iterator<Item = Self>, QuadbinError> {
+        let tiles = bbox_to_tiles(xmin, ymin, xmax, ymax, res)?;
+
+        // Column spans from west to east, i.e. eastern end of the grid
+        // first when crossing the antimeridian
+        let mut columns = [None; 2];
+        let mut rows = None;
+        if let Some(tiles) = tiles {
+            for (span, &bounds) in columns.iter_mut().zip(tiles.columns.iter().rev()) {
+                *span = Some(bounds);
+            }
+            rows = Some(tiles.rows.0..=tiles.rows.1);
+        }
+
+        Ok(rows.into_iter().flatten().flat_map(move |y| {
+            columns
+                .into_iter()
+                .flatten()
+                .flat_map(|(start, end)| start..=end)
+                .map(move |x| Tile::new(x, y, res).to_cell().expect("cell index"))
+        }))
+    }
+
+    /// Convert Web Mercator (EPSG:3857) coordinates in meters into a
+    /// Quadbin cell.
+    ///
+    /// Eastings are clipped to the extent of the projection, and
+    /// northings beyond it fall into the northernmost or southernmost
+    /// row, as latitudes do in [Cell::from_point].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::from_mercator(19455725.0, -5055810.0, 26).expect("cell index");
+    /// let [x, y] = cell.mercator_center();
+    /// assert!((x - 19455725.0).abs() < 1.0 && (y + 5055810.0).abs() < 1.0);
+    /// ```
+    pub fn from_mercator(x_m: f64, y_m: f64, res: u8) -> Result<Self, QuadbinError> {
+        let (x, y, z) = mercator_to_tile_fraction(x_m, y_m, res)?;
+        Tile::new(x.floor() as u32, y.floor() as u32, z).to_cell()
+    }
+
+    /// Convert a Quadbin cell into a bounding box in Web Mercator
+    /// (EPSG:3857) coordinates.
+    ///
+    /// Returns an array with [xmin, ymin, xmax, ymax] in meters.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let bbox = cell.to_mercator_bbox();
+    /// assert_eq!(bbox, [2504688.5428486555, -2504688.5428486555, 5009377.085697311, 0.0]);
+    /// ```
+    pub fn to_mercator_bbox(&self) -> [f64; 4] {
+        let tile = &self.to_tile();
+
+        let (xmin, ymin) = tile_to_mercator(tile, 0.0, 1.0);
+        let (xmax, ymax) = tile_to_mercator(tile, 1.0, 0.0);
+
+        [xmin, ymin, xmax, ymax]
+    }
+
+    /// Convert a Quadbin cell into its center in Web Mercator (EPSG:3857)
+    /// coordinates.
+    ///
+    /// Returns an array with x and y in meters, see also [Cell::to_point].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// assert_eq!(cell.mercator_center(), [3757032.814272983, -1252344.2714243277]);
+    /// ```
+    pub fn mercator_center(&self) -> [f64; 2] {
+        let (x, y) = tile_to_mercator(&self.to_tile(), 0.5, 0.5);
+        [x, y]
+    }
+
+    /// Convert a Quadbin cell into slippy map tile coordinates.
+    ///
+    /// Returns a tuple with zoom level, column and row, in the XYZ scheme
+    /// (row 0 at the north). This is equivalent to CARTO's `QUADBIN_TOZXY`.
+    ///
+    /// See also [Cell::from_zxy] and [Cell::to_tms].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// assert_eq!(cell.to_zxy(), (4, 9, 8));
+    /// ```
+    pub fn to_zxy(&self) -> (u8, u32, u32) {
+        let tile = self.to_tile();
+        (tile.z, tile.x, tile.y)
+    }
+
+    /// Convert slippy map tile coordinates into a Quadbin cell.
+    ///
+    /// Coordinates follow the XYZ scheme (row 0 at the north). This is
+    /// equivalent to CARTO's `QUADBIN_FROMZXY`.
+    ///
+    /// See also [Cell::to_zxy] and [Cell::from_tms].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `z` is greater than 26, and
+    /// [QuadbinError::InvalidTile] if `x` or `y` are beyond `2^z - 1`.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::from_zxy(4, 9, 8).expect("cell index");
+    /// assert_eq!(cell, Cell::new(5209574053332910079));
+    /// ```
+    pub fn from_zxy(z: u8, x: u32, y: u32) -> Result<Self, QuadbinError> {
+        zxy_to_cell(z, x, y)
+    }
+
+    /// Convert a Quadbin cell into TMS tile coordinates.
+    ///
+    /// Same as [Cell::to_zxy], but with the row axis flipped (row 0 at the
+    /// south), as used by MBTiles.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// assert_eq!(cell.to_tms(), (4, 9, 7));
+    /// ```
+    pub fn to_tms(&self) -> (u8, u32, u32) {
+        let (z, x, y) = self.to_zxy();
+        (z, x, flip_row(z, y))
+    }
+
+    /// Convert TMS tile coordinates into a Quadbin cell.
+    ///
+    /// Same as [Cell::from_zxy], but with the row axis flipped (row 0 at the
+    /// south), as used by MBTiles.
+    ///
+    /// # Errors
+    /// See [Cell::from_zxy].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::from_tms(4, 9, 7).expect("cell index");
+    /// assert_eq!(cell, Cell::new(5209574053332910079));
+    /// ```
+    pub fn from_tms(z: u8, x: u32, y: u32) -> Result<Self, QuadbinError> {
+        if z > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(z));
+        }
+        // Check the tile before flipping, so errors hold the caller's row
+        if x >= 1 << z || y >= 1 << z {
+            return Err(QuadbinError::InvalidTile(z, x, y));
+        }
+
+        zxy_to_cell(z, x, flip_row(z, y))
+    }
+
+    /// Convert a Quadbin cell into a Quadkey string.
+    ///
+    /// Quadkey is the Bing Maps Tile System index, with one digit per
+    /// resolution level. Resolution 0 cell yields an empty string.
+    ///
+    /// See also [Cell::from_quadkey].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::from_point(-41.28303675124842, 174.77727344223067, 23).expect("cell index");
+    /// assert_eq!(cell.to_quadkey(), "31311100030030030211121");
+    /// ```
+    pub fn to_quadkey(&self) -> String {
+        cell_to_quadkey(self)
+    }
+
+    /// Convert a Quadkey string into a Quadbin cell.
+    ///
+    /// See also [Cell::to_quadkey].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidQuadkeyDigit] if the key contains characters
+    /// other than `0`, `1`, `2` or `3`, and [QuadbinError::QuadkeyTooLong]
+    /// if it has more than 26 digits.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::from_quadkey("31").expect("cell index");
+    /// assert_eq!(cell, Cell::new(5201094619659501567));
+    /// ```
+    pub fn from_quadkey(quadkey: &str) -> Result<Self, QuadbinError> {
+        quadkey_to_cell(quadkey)
+    }
+
     /// Convert a Quadbin cell into a tile.
     pub(crate) fn to_tile(self) -> Tile {
         cell_to_tile(&self)
@@ -292,8 +1020,44 @@ impl fmt::Display for Cell {
     }
 }
 
-// TODO:
-// Detect direction from neighbor https://github.com/HydroniumLabs/h3o/blob/ad2bebf52eab218d66b0bf213b14a2802bf616f7/src/base_cell.rs#L135C1-L150C6
+impl fmt::LowerHex for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.get(), f)
+    }
+}
+
+impl fmt::UpperHex for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.get(), f)
+    }
+}
+
+/// Parse a cell index from a decimal or hexadecimal string.
+///
+/// Hexadecimal strings may be prefixed with `0x`. Strings made of decimal
+/// digits only are read as decimal first, then as hexadecimal if they do
+/// not hold a valid cell index.
+///
+/// # Errors
+/// [QuadbinError::InvalidCellString] if the string is not a number, and
+/// [QuadbinError::InvalidCell] if it is not a valid cell index.
+///
+/// # Example
+/// ```
+/// use qbin::Cell;
+///
+/// let cell = Cell::new(5209574053332910079);
+/// assert_eq!("5209574053332910079".parse::<Cell>(), Ok(cell));
+/// assert_eq!("484c1fffffffffff".parse::<Cell>(), Ok(cell));
+/// assert_eq!("0x484C1FFFFFFFFFFF".parse::<Cell>(), Ok(cell));
+/// ```
+impl FromStr for Cell {
+    type Err = QuadbinError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        str_to_cell(s)
+    }
+}
 
 // Internal functions ------------------------------------------------
 /// Quadbin cell validation
@@ -385,8 +1149,7 @@ fn point_to_cell(lat: f64, lng: f64, res: u8) -> Result<Cell, QuadbinError> {
 /// Convert cell into point
 fn cell_to_point(cell: &Cell) -> [f64; 2] {
     let tile = cell.to_tile();
-    let lat = tile.to_latitude(0.5).expect("offset");
-    let lon = tile.to_longitude(0.5).expect("offset");
+    let [lat, lon] = tile.to_point(0.5, 0.5).expect("offset");
 
     // Return array, not tuple, as it more memory efficient
     // See https://doc.rust-lang.org/stable/book/ch03-02-data-types.html#the-array-type
@@ -407,3 +1170,215 @@ fn cell_to_parent(cell: &Cell, parent_res: u8) -> Result<Cell, QuadbinError> {
 
     Cell::try_from(result)
 }
+
+/// Lowest and highest indexes of a cell's descendants at a resolution.
+fn cell_descendant_bounds(cell: &Cell, res: u8) -> Result<(u64, u64), QuadbinError> {
+    let resolution = cell.resolution();
+    if res < resolution || res > MAX_RESOLUTION {
+        return Err(QuadbinError::InvalidResolution(res));
+    }
+
+    // Morton bits below the cell's own level, all set in a valid index
+    let unused = FOOTER >> ((resolution as u64) << 1);
+    let base = (cell.get() & !(0x1F << 52)) | ((res as u64) << 52);
+
+    let min = (base & !unused) | (FOOTER >> ((res as u64) << 1));
+    let max = base | unused;
+
+    Ok((min, max))
+}
+
+/// Compact a set of cells, see [Cell::compact].
+fn compact_cells(cells: impl IntoIterator<Item = Cell>) -> Result<Vec<Cell>, QuadbinError> {
+    // Bucket cells by resolution, rejecting duplicates
+    let mut seen = CellSet::default();
+    let mut levels: [Vec<Cell>; MAX_RESOLUTION as usize + 1] = Default::default();
+    for cell in cells {
+        if !seen.insert(cell) {
+            return Err(QuadbinError::OverlappingCells(cell.get(), cell.get()));
+        }
+        levels[cell.resolution() as usize].push(cell);
+    }
+
+    // Reject cells that have an ancestor in the set
+    for cell in levels.iter().flatten() {
+        for res in 0..cell.resolution() {
+            let ancestor = cell.parent(res)?;
+            if seen.contains(&ancestor) {
+                return Err(QuadbinError::OverlappingCells(ancestor.get(), cell.get()));
+            }
+        }
+    }
+
+    // Merge complete sibling groups, from the finest resolution up
+    let mut compacted = Vec::with_capacity(seen.len());
+    for res in (1..=MAX_RESOLUTION as usize).rev() {
+        let parent_res = res as u8 - 1;
+        let mut level = std::mem::take(&mut levels[res]);
+        level.sort_unstable();
+
+        // Siblings are contiguous once sorted, as they share the same prefix
+        for group in level.chunk_by(|a, b| a.parent(parent_res) == b.parent(parent_res)) {
+            if group.len() == 4 {
+                levels[res - 1].push(group[0].parent(parent_res)?);
+            } else {
+                compacted.extend_from_slice(group);
+            }
+        }
+    }
+    compacted.append(&mut levels[0]);
+
+    compacted.sort_unstable();
+    Ok(compacted)
+}
+
+/// Expand a set of cells to a uniform resolution, see [Cell::uncompact].
+fn uncompact_cells(
+    cells: impl IntoIterator<Item = Cell>,
+    res: u8,
+) -> Result<Vec<Cell>, QuadbinError> {
+    if res > MAX_RESOLUTION {
+        return Err(QuadbinError::InvalidResolution(res));
+    }
+
+    let mut uncompacted = Vec::new();
+    for cell in cells {
+        let resolution = cell.resolution();
+        if resolution > res {
+            return Err(QuadbinError::InvalidResolution(res));
+        }
+
+        if resolution == res {
+            uncompacted.push(cell);
+        } else {
+            for child in cell.children(res)? {
+                uncompacted.push(child?);
+            }
+        }
+    }
+
+    Ok(uncompacted)
+}
+
+/// Find the direction from a cell to its neighbor at the same resolution.
+fn cell_direction_to_neighbor(cell: &Cell, other: &Cell) -> Option<Direction> {
+    if cell.resolution() != other.resolution() {
+        return None;
+    }
+
+    let from = cell.to_tile();
+    let to = other.to_tile();
+    let dx = to.x as i64 - from.x as i64;
+    let dy = to.y as i64 - from.y as i64;
+
+    match (dx, dy) {
+        (0, 0) => None,
+        (-1..=1, -1..=1) => Direction::from_offset(dx as i8, dy as i8),
+        _ => None,
+    }
+}
+
+/// Classify how two cells, possibly at different resolutions, touch.
+fn cell_adjacency(cell: &Cell, other: &Cell) -> Adjacency {
+    let a = cell.to_tile();
+    let b = other.to_tile();
+    let z = a.z.max(b.z);
+
+    // Half-open spans of both cells at the finest resolution
+    let span = |v: u32, tile_z: u8| {
+        let v = (v as i64) << (z - tile_z);
+        (v, v + (1_i64 << (z - tile_z)))
+    };
+    let overlap = |(a0, a1): (i64, i64), (b0, b1): (i64, i64)| a1.min(b1) - a0.max(b0);
+
+    let ox = overlap(span(a.x, a.z), span(b.x, b.z));
+    let oy = overlap(span(a.y, a.z), span(b.y, b.z));
+
+    match (ox.signum(), oy.signum()) {
+        (0, 1) | (1, 0) => Adjacency::SharesEdge,
+        (0, 0) => Adjacency::SharesCorner,
+        _ => Adjacency::NotAdjacent,
+    }
+}
+
+/// Convert XYZ tile coordinates into a cell, checking their range.
+fn zxy_to_cell(z: u8, x: u32, y: u32) -> Result<Cell, QuadbinError> {
+    if z > MAX_RESOLUTION {
+        return Err(QuadbinError::InvalidResolution(z));
+    }
+
+    let tiles_per_level = 1_u32 << z;
+    if x >= tiles_per_level || y >= tiles_per_level {
+        return Err(QuadbinError::InvalidTile(z, x, y));
+    }
+
+    Tile::new(x, y, z).to_cell()
+}
+
+/// Flip a tile row between XYZ and TMS schemes.
+fn flip_row(z: u8, y: u32) -> u32 {
+    (1_u32 << z) - 1 - y
+}
+
+/// Convert a cell into a Quadkey string.
+fn cell_to_quadkey(cell: &Cell) -> String {
+    let xy = cell.get() & FOOTER;
+
+    (1..=cell.resolution() as u64)
+        .map(|level| {
+            let digit = (xy >> (52 - 2 * level)) & 3;
+            char::from(b'0' + digit as u8)
+        })
+        .collect()
+}
+
+/// Convert a Quadkey string into a cell.
+fn quadkey_to_cell(quadkey: &str) -> Result<Cell, QuadbinError> {
+    let z = quadkey.chars().count();
+    if z > MAX_RESOLUTION as usize {
+        return Err(QuadbinError::QuadkeyTooLong(z));
+    }
+
+    let mut xy = 0_u64;
+    for (level, digit) in (1_u64..).zip(quadkey.chars()) {
+        let digit = match digit {
+            '0'..='3' => digit as u64 - '0' as u64,
+            _ => return Err(QuadbinError::InvalidQuadkeyDigit(digit)),
+        };
+        xy |= digit << (52 - 2 * level);
+    }
+
+    let z = z as u64;
+    let cell = HEADER | (1 << 59) | (z << 52) | xy | (FOOTER >> (z * 2));
+    Cell::try_from(cell)
+}
+
+/// Parse a cell index from a decimal or hexadecimal string.
+fn str_to_cell(s: &str) -> Result<Cell, QuadbinError> {
+    let invalid = || QuadbinError::InvalidCellString(s.to_string());
+
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        // Reject signs, which from_str_radix accepts
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let value = u64::from_str_radix(hex, 16).map_err(|_| invalid())?;
+        return Cell::try_from(value);
+    }
+
+    let decimal = s
+        .bytes()
+        .all(|b| b.is_ascii_digit())
+        .then(|| s.parse::<u64>().ok());
+    let hex = s
+        .bytes()
+        .all(|b| b.is_ascii_hexdigit())
+        .then(|| u64::from_str_radix(s, 16).ok());
+
+    match (decimal.flatten(), hex.flatten()) {
+        (Some(value), _) if is_valid_cell(value) => Cell::try_from(value),
+        (_, Some(value)) if is_valid_cell(value) => Cell::try_from(value),
+        (Some(value), _) | (None, Some(value)) => Err(QuadbinError::InvalidCell(Some(value))),
+        (None, None) => Err(invalid()),
+    }
+}
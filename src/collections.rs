@@ -0,0 +1,70 @@
+use crate::Cell;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// A [HashMap] keyed by [Cell], using [CellHasher].
+///
+/// # Example
+/// ```
+/// use qbin::{Cell, CellMap};
+///
+/// let mut counts = CellMap::default();
+/// for (lat, lng) in [(40.4168, -3.7038), (40.4169, -3.7037), (48.8566, 2.3522)] {
+///     let cell = Cell::from_point(lat, lng, 10).expect("cell index");
+///     *counts.entry(cell).or_insert(0) += 1;
+/// }
+/// assert_eq!(counts.len(), 2);
+/// ```
+pub type CellMap<V> = HashMap<Cell, V, BuildCellHasher>;
+
+/// A [HashSet] of [Cell], using [CellHasher].
+pub type CellSet = HashSet<Cell, BuildCellHasher>;
+
+/// Builder of [CellHasher], for use in [HashMap] and [HashSet].
+pub type BuildCellHasher = BuildHasherDefault<CellHasher>;
+
+/// A fast, non-cryptographic [Hasher] tuned for Quadbin cell indexes.
+///
+/// The upper bits of Quadbin indexes are nearly constant (header, mode and
+/// resolution), and the lower ones are filled with `1` below the cell's
+/// resolution. Using the index as its own hash would therefore put most
+/// cells into a handful of buckets. Instead, the index goes through the
+/// 64-bit finalizer of MurmurHash3, so every input bit affects every
+/// output bit.
+///
+/// Like the standard library's default hasher, it is not meant for
+/// keys provided by untrusted sources, as it offers no protection against
+/// HashDoS attacks.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CellHasher(u64);
+
+impl Hasher for CellHasher {
+    fn finish(&self) -> u64 {
+        fmix64(self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // Not used for cells, which are hashed as a single u64
+        for chunk in bytes.chunks(8) {
+            let mut buffer = [0; 8];
+            buffer[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(buffer));
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        // Mixing is deferred to `finish`, a single cell costs one round
+        self.0 = fmix64(self.0) ^ i;
+    }
+}
+
+// Internal functions ------------------------------------------------
+/// MurmurHash3's 64-bit finalizer.
+const fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    k ^= k >> 33;
+    k
+}
@@ -4,27 +4,21 @@ pub(crate) const MIN_LONGITUDE: f64 = -180.0;
 pub(crate) const MAX_LATITUDE: f64 = 89.0;
 pub(crate) const MIN_LATITUDE: f64 = -89.0;
 
+// Web Mercator (EPSG:3857) half-extent in meters, i.e. pi times the
+// WGS84 semi-major axis
+pub(crate) const MERCATOR_EXTENT: f64 = 20_037_508.342_789_244;
+
 // Resolution
 // https://docs.carto.com/data-and-analysis/analytics-toolbox-for-postgresql/key-concepts/spatial-indexes#quadbin
 pub(crate) const MAX_RESOLUTION: u8 = 26;
 
-// Area estimation
-pub(crate) const REF_AREA: f64 = 508164597540055.75;
-pub(crate) const AREA_FACTORS: &[f64; 12] = &[
-    1.0,
-    1.003_741_849_761_155,
-    1.897_097_273_904_830_4,
-    2.711_808_583_954_8,
-    3.034_250_040_669_436_4,
-    3.123_101_473_513_554,
-    3.145_758_804_577_431_6,
-    3.151_449_027_223_487,
-    3.152_873_167_713_691_4,
-    3.153_229_301_352_465_7,
-    3.153_318_340_910_941_8,
-    3.153_340_601_184_773_6,
-];
-pub(crate) const AF_LEN: u8 = AREA_FACTORS.len() as u8;
+// Earth models
+// WGS84 authalic radius, see https://en.wikipedia.org/wiki/Earth_radius#Authalic_radius
+pub(crate) const EARTH_RADIUS: f64 = 6_371_007.180_918_475;
+pub(crate) const WGS84_A: f64 = 6_378_137.0;
+pub(crate) const WGS84_F: f64 = 1.0 / 298.257_223_563;
+pub(crate) const WGS84_B: f64 = WGS84_A * (1.0 - WGS84_F);
+pub(crate) const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
 
 // Quadbin cell
 pub(crate) const HEADER: u64 = 0x4000_0000_0000_0000;
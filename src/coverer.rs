@@ -0,0 +1,240 @@
+use crate::Cell;
+use crate::constants::*;
+use crate::errors::QuadbinError;
+use crate::polyfill::{Areal, Relation, cell_relation};
+use core::cmp::Reverse;
+use geo::{Polygon, Rect};
+use std::collections::BinaryHeap;
+
+/// Approximate a polygon with a limited number of cells of mixed
+/// resolutions.
+///
+/// Unlike [Cell::from_polygon], which uses a single resolution, the
+/// coverer keeps coarse cells in the polygon's interior and refines them
+/// along its boundary, coarsest cells first, until the cell budget is
+/// spent or `max_res` is reached.
+///
+/// The budget may be exceeded when the polygon intersects more than
+/// `max_cells` cells at `min_res`, as no cell coarser than `min_res` is
+/// ever returned.
+///
+/// # Example
+/// ```
+/// use qbin::{Cell, RegionCoverer};
+/// use geo::polygon;
+///
+/// let polygon = polygon![
+///     (x: -3.9, y: 40.3),
+///     (x: -3.5, y: 40.3),
+///     (x: -3.5, y: 40.6),
+///     (x: -3.9, y: 40.6),
+/// ];
+///
+/// let coverer = RegionCoverer::new(4, 16, 20).expect("coverer");
+/// let exterior = coverer.exterior_covering(&polygon);
+/// let interior = coverer.interior_covering(&polygon);
+///
+/// assert!(exterior.len() <= 20);
+/// assert!(interior.len() <= 20);
+/// assert!(exterior.iter().all(|cell| (4..=16).contains(&cell.resolution())));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct RegionCoverer {
+    min_res: u8,
+    max_res: u8,
+    max_cells: usize,
+}
+
+impl RegionCoverer {
+    /// Create a coverer returning cells between `min_res` and `max_res`,
+    /// with at most `max_cells` cells.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `max_res` is greater than 26,
+    /// or `min_res` is greater than `max_res`.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::RegionCoverer;
+    ///
+    /// let coverer = RegionCoverer::new(4, 16, 20).expect("coverer");
+    /// assert_eq!(coverer.max_cells(), 20);
+    /// assert!(RegionCoverer::new(16, 4, 20).is_err());
+    /// ```
+    pub fn new(min_res: u8, max_res: u8, max_cells: usize) -> Result<Self, QuadbinError> {
+        if max_res > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(max_res));
+        }
+        if min_res > max_res {
+            return Err(QuadbinError::InvalidResolution(min_res));
+        }
+
+        Ok(RegionCoverer {
+            min_res,
+            max_res,
+            max_cells,
+        })
+    }
+
+    /// Coarsest resolution of the returned cells.
+    pub fn min_resolution(&self) -> u8 {
+        self.min_res
+    }
+
+    /// Finest resolution of the returned cells.
+    pub fn max_resolution(&self) -> u8 {
+        self.max_res
+    }
+
+    /// Cell budget of a covering.
+    pub fn max_cells(&self) -> usize {
+        self.max_cells
+    }
+
+    /// Compute a set of cells whose union contains the polygon.
+    ///
+    /// Cells are disjoint and returned in ascending index order.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, RegionCoverer};
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let coverer = RegionCoverer::new(0, 10, 8).expect("coverer");
+    /// assert_eq!(coverer.exterior_covering(&cell.to_polygon()), vec![cell]);
+    /// ```
+    pub fn exterior_covering(&self, polygon: &Polygon) -> Vec<Cell> {
+        self.cover(&Areal::from(polygon), false)
+    }
+
+    /// Compute a set of cells contained in the polygon.
+    ///
+    /// Cells are disjoint and returned in ascending index order. The
+    /// covering is empty for polygons too thin to contain a cell at
+    /// `max_res`.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, RegionCoverer};
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let coverer = RegionCoverer::new(0, 10, 8).expect("coverer");
+    /// assert_eq!(coverer.interior_covering(&cell.to_polygon()), vec![cell]);
+    /// ```
+    pub fn interior_covering(&self, polygon: &Polygon) -> Vec<Cell> {
+        self.cover(&Areal::from(polygon), true)
+    }
+
+    /// Refine candidates intersecting the geometry's boundary, coarsest
+    /// first, while the budget allows.
+    fn cover(&self, areal: &Areal, interior: bool) -> Vec<Cell> {
+        let mut covering = Covering {
+            cells: Vec::new(),
+            queue: BinaryHeap::new(),
+            max_res: self.max_res,
+            interior,
+        };
+        let Some(bounds) = areal.bounding_rect() else {
+            return covering.cells;
+        };
+
+        let start = self.smallest_ancestor(&bounds);
+        covering.seed(areal, &bounds, start, self.min_res);
+
+        // Cell indexes grow with resolution, so the heap yields the
+        // coarsest candidates first
+        while let Some(Reverse(cell)) = covering.queue.pop() {
+            let kids = cell
+                .children(cell.resolution() + 1)
+                .expect("valid resolution")
+                .map(|kid| kid.expect("cell index"))
+                .map(|kid| (kid, cell_relation(areal, &bounds, &kid)))
+                .filter(|(_, relation)| *relation != Relation::Disjoint)
+                .collect::<Vec<_>>();
+
+            if covering.cells.len() + covering.queue.len() + kids.len() > self.max_cells {
+                if !interior {
+                    covering.cells.push(cell);
+                }
+                continue;
+            }
+
+            for (kid, relation) in kids {
+                covering.add(kid, relation);
+            }
+        }
+
+        covering.cells.sort_unstable();
+        covering.cells
+    }
+
+    /// Find the finest cell, not finer than `max_res`, containing a
+    /// bounding box.
+    fn smallest_ancestor(&self, bounds: &Rect) -> Cell {
+        let north_west = Cell::from_point(bounds.max().y, bounds.min().x, self.max_res);
+        let south_east = Cell::from_point(bounds.min().y, bounds.max().x, self.max_res);
+        let (north_west, south_east) = (
+            north_west.expect("cell index"),
+            south_east.expect("cell index"),
+        );
+        if north_west == south_east {
+            return north_west;
+        }
+
+        (0..self.max_res)
+            .rev()
+            .map(|res| north_west.parent(res).expect("cell index"))
+            .find(|ancestor| south_east.parent(ancestor.resolution()) == Ok(*ancestor))
+            .expect("common ancestor")
+    }
+}
+
+// Internal functions ------------------------------------------------
+/// Covering under construction.
+struct Covering {
+    /// Cells kept in the covering.
+    cells: Vec<Cell>,
+    /// Candidates intersecting the boundary, to be refined.
+    queue: BinaryHeap<Reverse<Cell>>,
+    max_res: u8,
+    interior: bool,
+}
+
+impl Covering {
+    /// Add the cells intersecting the geometry at `min_res`, or `cell`
+    /// itself if finer, starting from `cell`.
+    fn seed(&mut self, areal: &Areal, bounds: &Rect, cell: Cell, min_res: u8) {
+        let relation = cell_relation(areal, bounds, &cell);
+        let resolution = cell.resolution();
+
+        match relation {
+            Relation::Disjoint => {}
+            Relation::Covered if resolution < min_res => {
+                let kids = cell.children(min_res).expect("valid resolution");
+                self.cells.extend(kids.map(|kid| kid.expect("cell index")));
+            }
+            Relation::Partial if resolution < min_res => {
+                let kids = cell.children(resolution + 1).expect("valid resolution");
+                for kid in kids {
+                    self.seed(areal, bounds, kid.expect("cell index"), min_res);
+                }
+            }
+            _ => self.add(cell, relation),
+        }
+    }
+
+    /// Keep a cell or queue it for refinement, depending on how it relates
+    /// to the geometry.
+    fn add(&mut self, cell: Cell, relation: Relation) {
+        match relation {
+            Relation::Disjoint => {}
+            Relation::Covered => self.cells.push(cell),
+            Relation::Partial if cell.resolution() < self.max_res => self.queue.push(Reverse(cell)),
+            Relation::Partial => {
+                if !self.interior {
+                    self.cells.push(cell);
+                }
+            }
+        }
+    }
+}
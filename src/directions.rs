@@ -2,23 +2,33 @@ use crate::errors::QuadbinError;
 use core::fmt;
 
 /// Maximum value for a direction.
-const MAX: u8 = 4;
+const MAX: u8 = 8;
 
 /// A direction within an rectangular grid.
 ///
 /// In Quadbin, each cell at level `N-1` is divided into 4 cells at the
 /// level `N`, with each sub-cell in one of the 4 possible directions (4 axes).
+/// Together with the diagonals, directions describe the 8 cells surrounding
+/// a cell, while `Center` points to the cell itself.
 ///
 /// ```text
-///       +-----+
-///       | Up  |
-/// +-----+-----+-----+
-/// |Left |Cell |Right|
-/// +-----+-----+-----+
-///       |Down |
-///       +-----+
+/// +--------+------+---------+
+/// | UpLeft |  Up  | UpRight |
+/// +--------+------+---------+
+/// |  Left  |Center|  Right  |
+/// +--------+------+---------+
+/// |DownLeft| Down |DownRight|
+/// +--------+------+---------+
 /// ```
+///
+/// With the `serde` feature, directions are serialized as their `u8`
+/// value.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
 #[repr(u8)]
 pub enum Direction {
     /// North.
@@ -29,12 +39,56 @@ pub enum Direction {
     Left = 2,
     /// South.
     Down = 3,
-} // TODO: Add Center as in h3o?
+    /// North-east.
+    UpRight = 4,
+    /// North-west.
+    UpLeft = 5,
+    /// South-east.
+    DownRight = 6,
+    /// South-west.
+    DownLeft = 7,
+    /// The cell itself.
+    Center = 8,
+}
+
+/// A set of directions surrounding a cell.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Neighborhood {
+    /// The 4 cells sharing an edge (`Up`, `Right`, `Left`, `Down`).
+    #[default]
+    VonNeumann,
+    /// The 8 cells sharing an edge or a corner.
+    Moore,
+}
+
+impl Neighborhood {
+    /// Number of directions in the neighborhood.
+    pub(crate) const fn len(self) -> usize {
+        match self {
+            Self::VonNeumann => 4,
+            Self::Moore => 8,
+        }
+    }
+}
+
+/// How two cells touch each other.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Adjacency {
+    /// Cells share an edge, or a part of it.
+    SharesEdge,
+    /// Cells share a single corner.
+    SharesCorner,
+    /// Cells do not touch, or overlap.
+    NotAdjacent,
+}
 
 // Updated after h3o crate
 // https://github.com/HydroniumLabs/h3o/blob/master/src/direction.rs
 impl Direction {
-    /// Iterates over the valid directions.
+    /// Iterates over the edge directions.
+    ///
+    /// Equivalent to [Direction::iter_neighborhood] with
+    /// [Neighborhood::VonNeumann].
     ///
     /// # Example
     ///
@@ -42,10 +96,59 @@ impl Direction {
     /// use qbin::Direction;
     ///
     /// let directions = Direction::iter().collect::<Vec<_>>();
+    /// assert_eq!(directions.len(), 4);
     /// ```
     pub fn iter() -> impl Iterator<Item = Self> {
-        // SAFETY: values from 0 to MAX are valid directions.
-        (0..=MAX).map(Self::new_unchecked)
+        Self::iter_neighborhood(Neighborhood::VonNeumann)
+    }
+
+    /// Iterates over the directions of a [Neighborhood].
+    ///
+    /// [Direction::Center] is never listed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qbin::{Direction, Neighborhood};
+    ///
+    /// let directions = Direction::iter_neighborhood(Neighborhood::Moore).collect::<Vec<_>>();
+    /// assert_eq!(directions.len(), 8);
+    /// assert!(!directions.contains(&Direction::Center));
+    /// ```
+    pub fn iter_neighborhood(neighborhood: Neighborhood) -> impl Iterator<Item = Self> {
+        // SAFETY: values below the neighborhood length are valid directions.
+        (0..neighborhood.len() as u8).map(Self::new_unchecked)
+    }
+
+    /// Column and row offsets of the direction, rows growing southwards.
+    pub(crate) const fn offset(self) -> (i8, i8) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Left => (-1, 0),
+            Self::Down => (0, 1),
+            Self::UpRight => (1, -1),
+            Self::UpLeft => (-1, -1),
+            Self::DownRight => (1, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Center => (0, 0),
+        }
+    }
+
+    /// Direction matching column and row offsets, see [Direction::offset].
+    pub(crate) const fn from_offset(dx: i8, dy: i8) -> Option<Self> {
+        match (dx, dy) {
+            (0, -1) => Some(Self::Up),
+            (1, 0) => Some(Self::Right),
+            (-1, 0) => Some(Self::Left),
+            (0, 1) => Some(Self::Down),
+            (1, -1) => Some(Self::UpRight),
+            (-1, -1) => Some(Self::UpLeft),
+            (1, 1) => Some(Self::DownRight),
+            (-1, 1) => Some(Self::DownLeft),
+            (0, 0) => Some(Self::Center),
+            _ => None,
+        }
     }
 
     /// Initializes a new [`Direction`] using a value that may be out of range.
@@ -70,6 +173,11 @@ impl TryFrom<u8> for Direction {
             1 => Ok(Self::Right),
             2 => Ok(Self::Left),
             3 => Ok(Self::Down),
+            4 => Ok(Self::UpRight),
+            5 => Ok(Self::UpLeft),
+            6 => Ok(Self::DownRight),
+            7 => Ok(Self::DownLeft),
+            8 => Ok(Self::Center),
             _ => Err(Self::Error::InvalidDirection(value)),
         }
     }
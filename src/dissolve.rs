@@ -0,0 +1,307 @@
+use crate::tiles::Tile;
+use crate::utils::*;
+use crate::{Cell, CellSet};
+use geo::{Coord, LineString, MultiPolygon, Polygon};
+use std::collections::HashMap;
+
+/// A point of the tile lattice at the finest resolution of the input.
+type Vertex = (u32, u32);
+
+/// A directed boundary edge, keeping the covered area on its left.
+type Edge = (Vertex, Vertex);
+
+/// Dissolve a set of cells into a [geo::MultiPolygon].
+///
+/// Unlike converting every cell with [Cell::to_polygon], adjacent cells are
+/// merged, giving one polygon per connected area, with holes where cells
+/// are missing. Cells may have any resolution, and cells covered by one
+/// of their ancestors are ignored.
+///
+/// Shared edges are cancelled exactly in tile coordinates before
+/// converting to degrees, so the output has no slivers or gaps. Exterior
+/// rings are counter-clockwise and holes clockwise. Areas touching at a
+/// single corner become separate polygons. Cells on both sides of the
+/// antimeridian are not merged.
+///
+/// # Example
+/// ```
+/// use qbin::{Cell, cells_to_multipolygon};
+///
+/// // Dissolving the children gives back the parent's outline
+/// let parent = Cell::new(5209574053332910079);
+/// let kids = parent
+///     .children(6)
+///     .expect("valid children")
+///     .collect::<Result<Vec<_>, _>>()
+///     .expect("cell index");
+///
+/// let outline = cells_to_multipolygon(kids);
+/// assert_eq!(outline.0.len(), 1);
+/// assert_eq!(outline.0[0].exterior().0.len(), 5);
+/// ```
+pub fn cells_to_multipolygon(cells: impl IntoIterator<Item = Cell>) -> MultiPolygon {
+    let cells = outermost_cells(cells);
+    let Some(res) = cells.iter().map(Cell::resolution).max() else {
+        return MultiPolygon::new(Vec::new());
+    };
+
+    let rings = link_rings(boundary_edges(&cells, res));
+    assemble(rings, res)
+}
+
+// Internal functions ------------------------------------------------
+/// Deduplicate cells and drop those covered by one of their ancestors.
+fn outermost_cells(cells: impl IntoIterator<Item = Cell>) -> Vec<Cell> {
+    let set = cells.into_iter().collect::<CellSet>();
+
+    set.iter()
+        .filter(|cell| {
+            (0..cell.resolution()).all(|res| !set.contains(&cell.parent(res).expect("cell index")))
+        })
+        .copied()
+        .collect()
+}
+
+/// Boundary edges of the cells, on the lattice of resolution `res`.
+///
+/// Cell sides are added as signed intervals along lattice lines, so sides
+/// shared by two cells cancel out whatever their resolutions.
+fn boundary_edges(cells: &[Cell], res: u8) -> Vec<Edge> {
+    // (line, position, delta) events along rows and columns, positive
+    // deltas going east or south
+    let mut rows = Vec::with_capacity(4 * cells.len());
+    let mut columns = Vec::with_capacity(4 * cells.len());
+    for cell in cells {
+        let tile = cell.to_tile();
+        let shift = res - tile.z;
+        let (x0, x1) = (tile.x << shift, (tile.x + 1) << shift);
+        let (y0, y1) = (tile.y << shift, (tile.y + 1) << shift);
+
+        // Southern side eastwards, northern side westwards
+        rows.extend([(y1, x0, 1), (y1, x1, -1), (y0, x0, -1), (y0, x1, 1)]);
+        // Western side southwards, eastern side northwards
+        columns.extend([(x0, y0, 1), (x0, y1, -1), (x1, y0, -1), (x1, y1, 1)]);
+    }
+
+    let mut edges = Vec::new();
+    for (y, start, end, sign) in sweep(rows) {
+        edges.push(if sign > 0 {
+            ((start, y), (end, y))
+        } else {
+            ((end, y), (start, y))
+        });
+    }
+    for (x, start, end, sign) in sweep(columns) {
+        edges.push(if sign > 0 {
+            ((x, start), (x, end))
+        } else {
+            ((x, end), (x, start))
+        });
+    }
+
+    edges
+}
+
+/// Sum signed intervals along each line, returning the maximal
+/// `(line, start, end, sign)` intervals with a non-zero sum.
+fn sweep(mut events: Vec<(u32, u32, i8)>) -> Vec<(u32, u32, u32, i8)> {
+    events.sort_unstable();
+
+    let mut intervals: Vec<(u32, u32, u32, i8)> = Vec::new();
+    for line in events.chunk_by(|a, b| a.0 == b.0) {
+        let positions = line.chunk_by(|a, b| a.1 == b.1).collect::<Vec<_>>();
+
+        let mut sum = 0;
+        for pair in positions.windows(2) {
+            let (line, start, end) = (pair[0][0].0, pair[0][0].1, pair[1][0].1);
+            sum += pair[0].iter().map(|event| event.2).sum::<i8>();
+            if sum == 0 {
+                continue;
+            }
+
+            match intervals.last_mut() {
+                Some(last) if last.0 == line && last.2 == start && last.3 == sum => last.2 = end,
+                _ => intervals.push((line, start, end, sum)),
+            }
+        }
+    }
+
+    intervals
+}
+
+/// Link boundary edges into simple rings.
+///
+/// Where cells touch only at a corner, the path turns left, so that each
+/// ring follows a single edge-connected area. Rings passing twice through
+/// such a corner, e.g. an area touching itself around a hole, are then
+/// split there.
+fn link_rings(mut edges: Vec<Edge>) -> Vec<Vec<Vertex>> {
+    edges.sort_unstable();
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+
+        let mut ring = Vec::new();
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let (from, to) = edges[current];
+            ring.push(from);
+
+            // Edges leaving the end vertex, at most two
+            let start = edges.partition_point(|edge| edge.0 < to);
+            let end = start + edges[start..].partition_point(|edge| edge.0 == to);
+            let next = match end - start {
+                1 => start,
+                _ => {
+                    let (dx, dy) = direction(edges[current]);
+                    (start..end)
+                        .find(|&i| direction(edges[i]) == (dy, -dx))
+                        .expect("left turn")
+                }
+            };
+
+            if next == first {
+                break;
+            }
+            current = next;
+        }
+
+        rings.extend(split_ring(ring));
+    }
+
+    rings
+}
+
+/// Unit direction of an edge.
+fn direction(((x0, y0), (x1, y1)): Edge) -> (i64, i64) {
+    (
+        (x1 as i64 - x0 as i64).signum(),
+        (y1 as i64 - y0 as i64).signum(),
+    )
+}
+
+/// Split a ring at repeated vertices into simple rings.
+fn split_ring(ring: Vec<Vertex>) -> Vec<Vec<Vertex>> {
+    let mut rings = Vec::new();
+    let mut path: Vec<Vertex> = Vec::with_capacity(ring.len());
+    let mut positions = HashMap::new();
+
+    for vertex in ring {
+        if let Some(&position) = positions.get(&vertex) {
+            let lobe = path.split_off(position);
+            for visited in &lobe {
+                positions.remove(visited);
+            }
+            rings.push(lobe);
+        }
+        positions.insert(vertex, path.len());
+        path.push(vertex);
+    }
+    rings.push(path);
+
+    rings
+}
+
+/// Twice the signed area of a ring in tile coordinates, negative for
+/// counter-clockwise rings on the map.
+fn twice_area(ring: &[Vertex]) -> i64 {
+    let mut area = 0;
+    for (i, &(x0, y0)) in ring.iter().enumerate() {
+        let (x1, y1) = ring[(i + 1) % ring.len()];
+        area += x0 as i64 * y1 as i64 - x1 as i64 * y0 as i64;
+    }
+    area
+}
+
+/// Center of the lattice square on the inner side of a hole's first edge,
+/// in doubled tile coordinates.
+fn inner_point(hole: &[Vertex]) -> (i64, i64) {
+    let (x, y) = hole[0];
+    let (dx, dy) = direction((hole[0], hole[1 % hole.len()]));
+    (2 * x as i64 + dx - dy, 2 * y as i64 + dy + dx)
+}
+
+/// Whether a ring contains a point given in doubled tile coordinates.
+///
+/// The point lies off lattice lines, so the ray never goes through a
+/// vertex.
+fn ring_contains(ring: &[Vertex], (px, py): (i64, i64)) -> bool {
+    let mut inside = false;
+    for (i, &(x0, y0)) in ring.iter().enumerate() {
+        let (x1, y1) = ring[(i + 1) % ring.len()];
+        let (x, ya, yb) = (2 * x0 as i64, 2 * y0 as i64, 2 * y1 as i64);
+        if x0 == x1 && x > px && (ya < py) != (yb < py) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Sort rings into exteriors and holes, attach each hole to the smallest
+/// exterior containing it, and convert to degrees.
+fn assemble(rings: Vec<Vec<Vertex>>, res: u8) -> MultiPolygon {
+    let mut exteriors = Vec::new();
+    let mut holes = Vec::new();
+    for ring in rings {
+        let area = twice_area(&ring);
+        if area < 0 {
+            exteriors.push((ring_bounds(&ring), -area, ring));
+        } else {
+            holes.push(ring);
+        }
+    }
+
+    let mut interiors = vec![Vec::new(); exteriors.len()];
+    for hole in holes {
+        let point = inner_point(&hole);
+        let parent = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, ([xmin, ymin, xmax, ymax], _, ring))| {
+                (*xmin..=*xmax).contains(&point.0)
+                    && (*ymin..=*ymax).contains(&point.1)
+                    && ring_contains(ring, point)
+            })
+            .min_by_key(|(_, (_, area, _))| *area)
+            .map(|(i, _)| i);
+
+        if let Some(i) = parent {
+            interiors[i].push(to_linestring(&hole, res));
+        }
+    }
+
+    exteriors
+        .into_iter()
+        .zip(interiors)
+        .map(|((_, _, ring), holes)| Polygon::new(to_linestring(&ring, res), holes))
+        .collect()
+}
+
+/// Bounds of a ring in doubled tile coordinates.
+fn ring_bounds(ring: &[Vertex]) -> [i64; 4] {
+    ring.iter().fold(
+        [i64::MAX, i64::MAX, i64::MIN, i64::MIN],
+        |[xmin, ymin, xmax, ymax], &(x, y)| {
+            let (x, y) = (2 * x as i64, 2 * y as i64);
+            [xmin.min(x), ymin.min(y), xmax.max(x), ymax.max(y)]
+        },
+    )
+}
+
+/// Convert a ring from tile coordinates to degrees.
+fn to_linestring(ring: &[Vertex], res: u8) -> LineString {
+    ring.iter()
+        .map(|&(x, y)| {
+            let tile = Tile::new(x, y, res);
+            Coord {
+                x: tile_to_longitude(&tile, 0.0).expect("offset"),
+                y: tile_to_latitude(&tile, 0.0).expect("offset"),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,67 @@
+use crate::constants::*;
+
+/// Shape of the Earth used for metric computations.
+///
+/// Quadbin cells are rectangles in longitude and latitude, so their
+/// areas and edge lengths have closed-form expressions on both models.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum EarthModel {
+    /// Sphere with the WGS84 authalic radius, i.e. with the same surface
+    /// as the ellipsoid.
+    Sphere,
+    /// WGS84 ellipsoid.
+    #[default]
+    Wgs84,
+}
+
+impl EarthModel {
+    /// Area between the equator and a latitude (in radians), per radian of
+    /// longitude, in square meters.
+    pub(crate) fn zonal_area(self, lat: f64) -> f64 {
+        let sinlat = lat.sin();
+
+        match self {
+            Self::Sphere => EARTH_RADIUS * EARTH_RADIUS * sinlat,
+            Self::Wgs84 => {
+                let e = WGS84_E2.sqrt();
+                let b2 = WGS84_B * WGS84_B;
+                let q = sinlat / (1.0 - WGS84_E2 * sinlat * sinlat) + (e * sinlat).atanh() / e;
+                0.5 * b2 * q
+            }
+        }
+    }
+
+    /// Length of a parallel arc at a latitude, over a longitude span (both
+    /// in radians), in meters.
+    pub(crate) fn parallel_length(self, lat: f64, dlng: f64) -> f64 {
+        match self {
+            Self::Sphere => EARTH_RADIUS * lat.cos() * dlng,
+            Self::Wgs84 => {
+                let sinlat = lat.sin();
+                let n = WGS84_A / (1.0 - WGS84_E2 * sinlat * sinlat).sqrt();
+                n * lat.cos() * dlng
+            }
+        }
+    }
+
+    /// Length of the meridian arc between the equator and a latitude (in
+    /// radians), in meters.
+    pub(crate) fn meridian_arc(self, lat: f64) -> f64 {
+        match self {
+            Self::Sphere => EARTH_RADIUS * lat,
+            // Helmert's series in the third flattening
+            // See https://en.wikipedia.org/wiki/Meridian_arc#Series_expansions
+            Self::Wgs84 => {
+                let n = WGS84_F / (2.0 - WGS84_F);
+                let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+                let scale = WGS84_A / (1.0 + n) * (1.0 + n2 / 4.0 + n4 / 64.0);
+
+                scale
+                    * (lat - (1.5 * n - 9.0 / 16.0 * n3) * (2.0 * lat).sin()
+                        + (15.0 / 16.0 * n2 - 15.0 / 32.0 * n4) * (4.0 * lat).sin()
+                        - 35.0 / 48.0 * n3 * (6.0 * lat).sin()
+                        + 315.0 / 512.0 * n4 * (8.0 * lat).sin())
+            }
+        }
+    }
+}
@@ -2,11 +2,18 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QuadbinError {
     InvalidDirection(u8),
     InvalidCell(Option<u64>),
     InvalidResolution(u8),
     InvalidOffset(f64),
+    OverlappingCells(u64, u64),
+    ResolutionMismatch(u8, u8),
+    InvalidQuadkeyDigit(char),
+    QuadkeyTooLong(usize),
+    InvalidTile(u8, u32, u32),
+    InvalidCellString(String),
 }
 
 impl fmt::Display for QuadbinError {
@@ -20,6 +27,22 @@ impl fmt::Display for QuadbinError {
                 e
             ),
             QuadbinError::InvalidOffset(msg) => write!(f, "invalid offset: {}", msg),
+            QuadbinError::OverlappingCells(a, b) => {
+                write!(f, "overlapping cells: {} overlaps {}", a, b)
+            }
+            QuadbinError::ResolutionMismatch(a, b) => {
+                write!(f, "resolution mismatch: {} and {}", a, b)
+            }
+            QuadbinError::InvalidQuadkeyDigit(e) => write!(f, "invalid quadkey digit: {:?}", e),
+            QuadbinError::QuadkeyTooLong(e) => write!(
+                f,
+                "Quadkey too long: {} digits. Accepted length is up to 26 digits, inclusive",
+                e
+            ),
+            QuadbinError::InvalidTile(z, x, y) => {
+                write!(f, "invalid tile z/x/y: {}/{}/{}", z, x, y)
+            }
+            QuadbinError::InvalidCellString(e) => write!(f, "invalid cell string: {:?}", e),
         }
     }
 }
@@ -1,6 +1,26 @@
-use crate::Cell;
+use crate::constants::*;
 use crate::errors::*;
-use geo::{LineString, MultiPoint, Point, Polygon};
+use crate::polyfill::{Areal, fill};
+use crate::tiles::Tile;
+use crate::utils::*;
+use crate::{Cell, CellSet, ContainmentMode};
+use geo::{
+    Geodesic, Geometry, InterpolatePoint, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Rect, unary_union,
+};
+
+/// Path followed by a line between two consecutive vertices.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Interpolation {
+    /// Straight segment in Web Mercator, i.e. a rhumb line. Longitudes
+    /// are followed as given, so a segment from 179° to -179° goes the
+    /// long way around.
+    #[default]
+    Rhumb,
+    /// Shortest path on the WGS84 ellipsoid, crossing the antimeridian
+    /// when shorter.
+    Geodesic,
+}
 
 /// Support for geospatial primitive types from [geo] crate.
 impl Cell {
@@ -52,6 +72,191 @@ impl Cell {
             .map(move |point| Cell::from_geopoint(point, res))
     }
 
+    /// List all cells intersecting a [geo::Rect] at a specific resolution.
+    ///
+    /// Similar to [Cell::from_bbox], but requires a [geo::Rect] for input.
+    /// As rectangles are normalized, they never cross the antimeridian.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    /// use geo::*;
+    ///
+    /// let rect = Rect::new(coord! { x: -3.9, y: 40.3 }, coord! { x: -3.5, y: 40.6 });
+    /// let cells = Cell::from_rect(rect, 10).expect("cells").collect::<Vec<_>>();
+    /// assert_eq!(cells.len(), 6);
+    /// ```
+    pub fn from_rect(rect: Rect, res: u8) -> Result<impl Iterator<Item = Self>, QuadbinError> {
+        let (min, max) = (rect.min(), rect.max());
+        Cell::from_bbox(min.x, min.y, max.x, max.y, res)
+    }
+
+    /// Compute the Quadbin cells touched by a [geo::LineString] at a
+    /// specific resolution.
+    ///
+    /// Segments are straight in Web Mercator, see
+    /// [Cell::from_linestring_with] for geodesic segments. Every cell
+    /// crossed by the line is listed, so consecutive cells share an edge
+    /// and there are no gaps between vertices. When the line passes
+    /// exactly through a corner, the cell on its horizontal side is
+    /// listed.
+    ///
+    /// Cells are listed in the order they are first reached along the
+    /// line, without duplicates.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    /// use geo::*;
+    ///
+    /// let line = line_string![(x: -3.7038, y: 40.4168), (x: 2.3522, y: 48.8566)];
+    /// let cells = Cell::from_linestring(&line, 10).expect("cells");
+    ///
+    /// let madrid = Cell::from_point(40.4168, -3.7038, 10).expect("cell index");
+    /// let paris = Cell::from_point(48.8566, 2.3522, 10).expect("cell index");
+    /// assert_eq!(cells.first(), Some(&madrid));
+    /// assert_eq!(cells.last(), Some(&paris));
+    /// ```
+    pub fn from_linestring(line: &LineString, res: u8) -> Result<Vec<Self>, QuadbinError> {
+        Cell::from_linestring_with(line, res, Interpolation::Rhumb)
+    }
+
+    /// Compute the Quadbin cells touched by a [geo::LineString] at a
+    /// specific resolution, with a specific [Interpolation] between
+    /// vertices.
+    ///
+    /// Geodesic segments are densified adaptively, until they do not
+    /// deviate from a straight Mercator segment by more than a small
+    /// fraction of a cell.
+    ///
+    /// See [Cell::from_linestring].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Interpolation};
+    /// use geo::*;
+    ///
+    /// // Fiji to Samoa, across the antimeridian
+    /// let line = line_string![(x: 178.0, y: -17.7), (x: -172.1, y: -13.8)];
+    /// let rhumb = Cell::from_linestring_with(&line, 8, Interpolation::Rhumb).expect("cells");
+    /// let geodesic = Cell::from_linestring_with(&line, 8, Interpolation::Geodesic).expect("cells");
+    /// assert!(geodesic.len() < rhumb.len());
+    /// ```
+    pub fn from_linestring_with(
+        line: &LineString,
+        res: u8,
+        interpolation: Interpolation,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        if res > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(res));
+        }
+
+        let mut cells = LineCover::new(res, interpolation);
+        cells.add_linestring(line);
+        Ok(cells.cells)
+    }
+
+    /// Compute the Quadbin cells touched by a [geo::MultiLineString] at a
+    /// specific resolution.
+    ///
+    /// Lines are covered one after the other, see [Cell::from_linestring].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    pub fn from_multilinestring(
+        lines: &MultiLineString,
+        res: u8,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        Cell::from_multilinestring_with(lines, res, Interpolation::Rhumb)
+    }
+
+    /// Compute the Quadbin cells touched by a [geo::MultiLineString] at a
+    /// specific resolution, with a specific [Interpolation] between
+    /// vertices.
+    ///
+    /// See [Cell::from_linestring_with].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    pub fn from_multilinestring_with(
+        lines: &MultiLineString,
+        res: u8,
+        interpolation: Interpolation,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        if res > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(res));
+        }
+
+        let mut cells = LineCover::new(res, interpolation);
+        for line in lines {
+            cells.add_linestring(line);
+        }
+        Ok(cells.cells)
+    }
+
+    /// Compute the Quadbin cells covering any [geo::Geometry] at a specific
+    /// resolution.
+    ///
+    /// Areal geometries, i.e. polygons, multi-polygons, rectangles and
+    /// triangles, are filled following [ContainmentMode], see
+    /// [Cell::from_polygon]. Points and lines have no area, so every mode
+    /// behaves like [ContainmentMode::Intersects]: points map to the cell
+    /// containing them, and lines to every cell they cross, see
+    /// [Cell::from_linestring]. Areal members of a geometry collection are
+    /// filled together, so a cell covered by adjacent polygons is kept
+    /// even if none of them covers it alone.
+    ///
+    /// Cells are returned in ascending index order, without duplicates.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, ContainmentMode};
+    /// use geo::*;
+    ///
+    /// let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+    ///     Geometry::Point(point!(x: -3.7038, y: 40.4168)),
+    ///     Geometry::Line(Line::new(coord! { x: -3.7038, y: 40.4168 }, coord! { x: -3.5, y: 40.5 })),
+    /// ]));
+    ///
+    /// let cells = Cell::cover_geometry(&collection, 12, ContainmentMode::Center).expect("cells");
+    /// assert!(cells.contains(&Cell::from_point(40.4168, -3.7038, 12).expect("cell index")));
+    /// assert!(cells.is_sorted());
+    /// ```
+    pub fn cover_geometry(
+        geometry: &Geometry,
+        res: u8,
+        mode: ContainmentMode,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        if res > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(res));
+        }
+
+        let mut cells = Vec::new();
+        let mut areals = Vec::new();
+        cover_geometry(geometry, res, &mut cells, &mut areals)?;
+
+        // Areal members are merged, so that cells covered by several of
+        // them at once are kept under every mode
+        match &areals[..] {
+            [] => {}
+            [areal] => cells.extend(fill(&Areal::from(areal), res, mode)?),
+            _ => cells.extend(fill(&Areal::from(&unary_union(&areals)), res, mode)?),
+        }
+        cells.sort_unstable();
+        cells.dedup();
+
+        Ok(cells)
+    }
+
     /// Converts Quadbin cell into [geo::Polygon]
     ///
     /// # Example
@@ -76,4 +281,152 @@ impl Cell {
             vec![],
         )
     }
+
+    /// Converts Quadbin cell into [geo::Polygon] in Web Mercator
+    /// (EPSG:3857) coordinates.
+    ///
+    /// Same as [Cell::to_polygon], with coordinates in meters.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    /// use geo::Area;
+    ///
+    /// // A resolution 1 cell covers a quarter of the map
+    /// let polygon = Cell::from_zxy(1, 0, 0).expect("cell index").to_mercator_polygon();
+    /// assert_eq!(polygon.unsigned_area(), 20037508.342789244_f64.powi(2));
+    /// ```
+    pub fn to_mercator_polygon(&self) -> Polygon {
+        let bbox = self.to_mercator_bbox();
+        Polygon::new(
+            LineString::from(vec![
+                (bbox[0], bbox[1]), // bottom-left
+                (bbox[2], bbox[1]), // bottom-right
+                (bbox[2], bbox[3]), // top-right
+                (bbox[0], bbox[3]), // top-left
+                (bbox[0], bbox[1]), // back to bottom-left to close the loop
+            ]),
+            vec![],
+        )
+    }
+}
+
+// Internal functions ------------------------------------------------
+/// Collect the cells covering the points and lines of a geometry, in no
+/// particular order, and its areal members for filling.
+fn cover_geometry(
+    geometry: &Geometry,
+    res: u8,
+    cells: &mut Vec<Cell>,
+    areals: &mut Vec<MultiPolygon>,
+) -> Result<(), QuadbinError> {
+    match geometry {
+        Geometry::Point(point) => cells.push(Cell::from_geopoint(*point, res)?),
+        Geometry::MultiPoint(points) => {
+            for point in points {
+                cells.push(Cell::from_geopoint(*point, res)?);
+            }
+        }
+        Geometry::Line(line) => {
+            let line = LineString::from(vec![line.start, line.end]);
+            cells.extend(Cell::from_linestring(&line, res)?);
+        }
+        Geometry::LineString(line) => cells.extend(Cell::from_linestring(line, res)?),
+        Geometry::MultiLineString(lines) => {
+            cells.extend(Cell::from_multilinestring(lines, res)?);
+        }
+        Geometry::Polygon(polygon) => areals.push(MultiPolygon::from(polygon.clone())),
+        Geometry::MultiPolygon(polygons) => areals.push(polygons.clone()),
+        Geometry::Rect(rect) => areals.push(MultiPolygon::from(rect.to_polygon())),
+        Geometry::Triangle(triangle) => areals.push(MultiPolygon::from(triangle.to_polygon())),
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection {
+                cover_geometry(geometry, res, cells, areals)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Maximum deviation, in tile units, of a geodesic from its chord.
+const GEODESIC_TOLERANCE: f64 = 0.05;
+/// Maximum number of geodesic bisections.
+const GEODESIC_DEPTH: u8 = 24;
+
+/// Cells touched by lines, without duplicates.
+struct LineCover {
+    res: u8,
+    interpolation: Interpolation,
+    seen: CellSet,
+    cells: Vec<Cell>,
+}
+
+impl LineCover {
+    fn new(res: u8, interpolation: Interpolation) -> Self {
+        Self {
+            res,
+            interpolation,
+            seen: CellSet::default(),
+            cells: Vec::new(),
+        }
+    }
+
+    fn add_linestring(&mut self, line: &LineString) {
+        let points = line.points().collect::<Vec<_>>();
+        if let [point] = points[..] {
+            // A single vertex is a degenerate segment
+            let units = point_to_tile_units(point.y(), point.x(), self.res);
+            self.add_segment(units, units);
+        }
+
+        for pair in points.windows(2) {
+            match self.interpolation {
+                Interpolation::Rhumb => {
+                    let from = point_to_tile_units(pair[0].y(), pair[0].x(), self.res);
+                    let to = point_to_tile_units(pair[1].y(), pair[1].x(), self.res);
+                    self.add_segment(from, to);
+                }
+                Interpolation::Geodesic => self.add_geodesic(pair[0], pair[1], 0),
+            }
+        }
+    }
+
+    /// Bisect a geodesic until it is close enough to a straight segment.
+    fn add_geodesic(&mut self, start: Point, end: Point, depth: u8) {
+        let z2 = (1_u64 << self.res) as f64;
+        let from = point_to_tile_units(start.y(), start.x(), self.res);
+        let to = unwrap_units(from, point_to_tile_units(end.y(), end.x(), self.res), z2);
+
+        if depth < GEODESIC_DEPTH {
+            let mid = Geodesic.point_at_ratio_between(start, end, 0.5);
+            let (mx, my) = unwrap_units(from, point_to_tile_units(mid.y(), mid.x(), self.res), z2);
+            let deviation = (mx - (from.0 + to.0) / 2.0).hypot(my - (from.1 + to.1) / 2.0);
+
+            // Long segments are always split, their midpoint may lie close
+            // to the chord by chance
+            let long = (to.0 - from.0).abs().max((to.1 - from.1).abs()) > z2 / 64.0;
+            if deviation > GEODESIC_TOLERANCE || long {
+                self.add_geodesic(start, mid, depth + 1);
+                self.add_geodesic(mid, end, depth + 1);
+                return;
+            }
+        }
+
+        self.add_segment(from, to);
+    }
+
+    fn add_segment(&mut self, from: (f64, f64), to: (f64, f64)) {
+        segment_to_tiles(from, to, self.res, |tile: Tile| {
+            let cell = tile.to_cell().expect("cell index");
+            if self.seen.insert(cell) {
+                self.cells.push(cell);
+            }
+        });
+    }
+}
+
+/// Shift a point by whole turns, to the closest position from `origin`.
+fn unwrap_units(origin: (f64, f64), (x, y): (f64, f64), z2: f64) -> (f64, f64) {
+    (x + z2 * ((origin.0 - x) / z2).round(), y)
 }
@@ -0,0 +1,263 @@
+use crate::errors::QuadbinError;
+use crate::utils::*;
+use crate::{Cell, Neighborhood};
+
+/// Horizontal topology of the Quadbin grid.
+///
+/// Web Mercator wraps around the antimeridian, however the original
+/// Quadbin implementations treat the grid as bounded on all sides.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Topology {
+    /// Grid ends at the first and last columns (x = 0 and x = 2^z − 1).
+    #[default]
+    Bounded,
+    /// Grid wraps horizontally across the ±180° meridian.
+    Cylindrical,
+}
+
+/// Metric used to count grid steps between two cells.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Metric {
+    /// Diagonal steps allowed, i.e. `max(|dx|, |dy|)`.
+    #[default]
+    Chebyshev,
+    /// Only edge steps allowed, i.e. `|dx| + |dy|`.
+    Manhattan,
+}
+
+/// Grid traversal.
+impl Cell {
+    /// List all cells within `k` grid steps of this cell, itself included.
+    ///
+    /// Steps are counted with the Chebyshev distance, i.e. diagonal
+    /// neighbors are one step away. This is equivalent to CARTO's
+    /// `QUADBIN_KRING`.
+    ///
+    /// Cells are listed row by row, from north-west to south-east. Cells
+    /// beyond the grid edges are omitted, see [Cell::grid_disk_with] to
+    /// wrap across the antimeridian.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let disk = cell.grid_disk(1);
+    /// assert_eq!(disk.len(), 9);
+    /// assert_eq!(disk[4], cell);
+    /// ```
+    pub fn grid_disk(&self, k: u32) -> Vec<Self> {
+        self.grid_disk_with(k, Topology::Bounded)
+    }
+
+    /// List all cells within `k` grid steps of this cell, using a specific
+    /// [Topology].
+    ///
+    /// See [Cell::grid_disk].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Topology};
+    ///
+    /// // First column of the grid
+    /// let cell = Cell::from_point(0.0, -179.9, 4).expect("cell index");
+    /// assert_eq!(cell.grid_disk_with(1, Topology::Bounded).len(), 6);
+    /// assert_eq!(cell.grid_disk_with(1, Topology::Cylindrical).len(), 9);
+    /// ```
+    pub fn grid_disk_with(&self, k: u32, topology: Topology) -> Vec<Self> {
+        tile_disk(&self.to_tile(), k, topology)
+            .into_iter()
+            .map(|(tile, _)| tile.to_cell().expect("cell index"))
+            .collect()
+    }
+
+    /// List all cells within `k` grid steps of this cell, together with
+    /// their distance to it.
+    ///
+    /// This is equivalent to CARTO's `QUADBIN_KRING_DISTANCES`. See
+    /// [Cell::grid_disk] for ordering and edge handling.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let disk = cell.grid_disk_distances(2);
+    /// assert_eq!(disk.len(), 25);
+    /// assert_eq!(disk.iter().filter(|(_, k)| *k == 2).count(), 16);
+    /// ```
+    pub fn grid_disk_distances(&self, k: u32) -> Vec<(Self, u32)> {
+        self.grid_disk_distances_with(k, Topology::Bounded)
+    }
+
+    /// List all cells within `k` grid steps of this cell, together with
+    /// their distance to it, using a specific [Topology].
+    ///
+    /// See [Cell::grid_disk_distances].
+    pub fn grid_disk_distances_with(&self, k: u32, topology: Topology) -> Vec<(Self, u32)> {
+        tile_disk(&self.to_tile(), k, topology)
+            .into_iter()
+            .map(|(tile, distance)| (tile.to_cell().expect("cell index"), distance))
+            .collect()
+    }
+
+    /// List cells exactly `k` grid steps away from this cell.
+    ///
+    /// Steps are counted with the Chebyshev distance, see
+    /// [Cell::grid_disk]. Cells are listed clockwise, starting from the
+    /// north-west corner of the ring: along the northern row from west to
+    /// east, down the eastern column, along the southern row from east to
+    /// west, and up the western column. Cells beyond the grid edges are
+    /// skipped, see [Cell::grid_ring_with] to wrap across the antimeridian.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// assert_eq!(cell.grid_ring(0), vec![cell]);
+    /// assert_eq!(cell.grid_ring(2).len(), 16);
+    /// ```
+    pub fn grid_ring(&self, k: u32) -> Vec<Self> {
+        self.grid_ring_with(k, Topology::Bounded)
+    }
+
+    /// List cells exactly `k` grid steps away from this cell, using a
+    /// specific [Topology].
+    ///
+    /// See [Cell::grid_ring].
+    pub fn grid_ring_with(&self, k: u32, topology: Topology) -> Vec<Self> {
+        tile_ring(&self.to_tile(), k, topology)
+            .into_iter()
+            .map(|tile| tile.to_cell().expect("cell index"))
+            .collect()
+    }
+
+    /// Compute the number of grid steps between two cells.
+    ///
+    /// Steps are counted with the Chebyshev distance on a bounded grid.
+    /// This is equivalent to CARTO's `QUADBIN_DISTANCE`.
+    ///
+    /// # Errors
+    /// [QuadbinError::ResolutionMismatch] if cells are at different
+    /// resolutions.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let madrid = Cell::from_point(40.4168, -3.7038, 10).expect("cell index");
+    /// let paris = Cell::from_point(48.8566, 2.3522, 10).expect("cell index");
+    /// assert_eq!(madrid.grid_distance(&paris), Ok(34));
+    /// ```
+    pub fn grid_distance(&self, other: &Cell) -> Result<u32, QuadbinError> {
+        self.grid_distance_with(other, Metric::Chebyshev, Topology::Bounded)
+    }
+
+    /// Compute the number of grid steps between two cells, using a specific
+    /// [Metric] and [Topology].
+    ///
+    /// See [Cell::grid_distance].
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Metric, Topology};
+    ///
+    /// let fiji = Cell::from_point(-17.7, 178.0, 8).expect("cell index");
+    /// let samoa = Cell::from_point(-13.8, -172.1, 8).expect("cell index");
+    ///
+    /// let bounded = fiji.grid_distance_with(&samoa, Metric::Manhattan, Topology::Bounded);
+    /// let wrapped = fiji.grid_distance_with(&samoa, Metric::Manhattan, Topology::Cylindrical);
+    /// assert!(wrapped.expect("distance") < bounded.expect("distance"));
+    /// ```
+    pub fn grid_distance_with(
+        &self,
+        other: &Cell,
+        metric: Metric,
+        topology: Topology,
+    ) -> Result<u32, QuadbinError> {
+        if self.resolution() != other.resolution() {
+            return Err(QuadbinError::ResolutionMismatch(
+                self.resolution(),
+                other.resolution(),
+            ));
+        }
+
+        Ok(tile_distance(
+            &self.to_tile(),
+            &other.to_tile(),
+            metric,
+            topology,
+        ))
+    }
+
+    /// List the cells traversed by a straight line between two cells,
+    /// both ends included.
+    ///
+    /// The line joins both cells' centers in tile coordinates and consecutive
+    /// cells share an edge or a corner, i.e. the path is 8-connected. See
+    /// [Cell::grid_path_cells_with] for a 4-connected path.
+    ///
+    /// # Errors
+    /// [QuadbinError::ResolutionMismatch] if cells are at different
+    /// resolutions.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let start = Cell::from_point(-41.2865, 174.7762, 12).expect("cell index");
+    /// let end = Cell::from_point(-41.1, 175.0, 12).expect("cell index");
+    ///
+    /// let path = start.grid_path_cells(&end).expect("path");
+    /// assert_eq!(path.first(), Some(&start));
+    /// assert_eq!(path.last(), Some(&end));
+    /// assert_eq!(path.len() as u32, start.grid_distance(&end).expect("distance") + 1);
+    /// ```
+    pub fn grid_path_cells(&self, other: &Cell) -> Result<Vec<Self>, QuadbinError> {
+        self.grid_path_cells_with(other, Neighborhood::Moore)
+    }
+
+    /// List the cells traversed by a straight line between two cells, with
+    /// a specific connectivity.
+    ///
+    /// With [Neighborhood::VonNeumann], consecutive cells always share an
+    /// edge: every cell crossed by the line is listed (supercover), and
+    /// when the line passes exactly through a corner, the horizontal step
+    /// is taken first. With [Neighborhood::Moore], diagonal steps are
+    /// allowed, see [Cell::grid_path_cells].
+    ///
+    /// # Errors
+    /// [QuadbinError::ResolutionMismatch] if cells are at different
+    /// resolutions.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, Direction, Neighborhood};
+    ///
+    /// let start = Cell::new(5209574053332910079);
+    /// let end = start.neighbor(Direction::DownRight).expect("cell index");
+    ///
+    /// let path = start.grid_path_cells_with(&end, Neighborhood::VonNeumann).expect("path");
+    /// assert_eq!(path, vec![start, start.neighbor(Direction::Right).expect("cell index"), end]);
+    /// ```
+    pub fn grid_path_cells_with(
+        &self,
+        other: &Cell,
+        neighborhood: Neighborhood,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        if self.resolution() != other.resolution() {
+            return Err(QuadbinError::ResolutionMismatch(
+                self.resolution(),
+                other.resolution(),
+            ));
+        }
+
+        let path = tile_path(&self.to_tile(), &other.to_tile(), neighborhood)
+            .into_iter()
+            .map(|tile| tile.to_cell().expect("cell index"))
+            .collect();
+
+        Ok(path)
+    }
+}
@@ -4,14 +4,46 @@
 mod cells;
 mod geo;
 pub use crate::cells::Cell;
+pub use crate::geo::Interpolation;
+
+// Polygon filling
+mod polyfill;
+pub use crate::polyfill::ContainmentMode;
+
+// Mixed-resolution coverings
+mod coverer;
+pub use crate::coverer::RegionCoverer;
+
+// Cell set outlines
+mod dissolve;
+pub use crate::dissolve::cells_to_multipolygon;
+
+// Range decomposition
+mod ranges;
+
+// Grid traversal
+mod grid;
+pub use crate::grid::{Metric, Topology};
 
 // Direction struct
 mod directions;
-pub use crate::directions::Direction;
+pub use crate::directions::{Adjacency, Direction, Neighborhood};
+
+// Cell collections
+mod collections;
+pub use crate::collections::{BuildCellHasher, CellHasher, CellMap, CellSet};
+
+// Earth models
+mod earth;
+pub use crate::earth::EarthModel;
 
 // Errors
 pub mod errors;
 
+// Serialization helpers
+#[cfg(feature = "serde")]
+pub mod serde_cell;
+
 // Internal stuff
 mod constants;
 mod tiles;
@@ -0,0 +1,232 @@
+use crate::Cell;
+use crate::constants::*;
+use crate::errors::QuadbinError;
+use crate::tiles::Tile;
+use geo::coordinate_position::CoordPos;
+use geo::dimensions::Dimensions;
+use geo::relate::IntersectionMatrix;
+use geo::{
+    BoundingRect, Intersects, MultiPolygon, Point, Polygon, PreparedGeometry, Rect, Relate, coord,
+};
+
+/// Rule deciding whether a cell belongs to a polyfill.
+///
+/// Mirrors the `mode` argument of CARTO's `QUADBIN_POLYFILL`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ContainmentMode {
+    /// Cell's center lies inside the polygon or on its boundary.
+    #[default]
+    Center,
+    /// Cell is fully contained in the polygon.
+    Contains,
+    /// Cell shares some area with the polygon.
+    Intersects,
+}
+
+/// Polygon filling.
+impl Cell {
+    /// Compute the Quadbin cells covering a [geo::Polygon] at a specific
+    /// resolution.
+    ///
+    /// Interior rings are honoured. The cell pyramid is walked top-down,
+    /// so whole branches outside or inside the polygon are resolved
+    /// without visiting every cell of its bounding box.
+    ///
+    /// Cells are returned in ascending index order.
+    ///
+    /// See [ContainmentMode] for the available modes.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, ContainmentMode};
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let polygon = cell.to_polygon();
+    ///
+    /// let cells = Cell::from_polygon(&polygon, 5, ContainmentMode::Contains).expect("cells");
+    /// let kids = cell.children(5).expect("children").collect::<Result<Vec<_>, _>>();
+    /// assert_eq!(Ok(cells), kids);
+    /// ```
+    pub fn from_polygon(
+        polygon: &Polygon,
+        res: u8,
+        mode: ContainmentMode,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        if res > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(res));
+        }
+
+        fill(&Areal::from(polygon), res, mode)
+    }
+
+    /// Compute the Quadbin cells covering a [geo::MultiPolygon] at a
+    /// specific resolution.
+    ///
+    /// Polygons are expected not to overlap, as per the OGC
+    /// specification. See [Cell::from_polygon].
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::{Cell, ContainmentMode};
+    /// use geo::MultiPolygon;
+    ///
+    /// let cell = Cell::new(5209574053332910079);
+    /// let polygons = MultiPolygon::new(vec![cell.to_polygon()]);
+    ///
+    /// let cells = Cell::from_multipolygon(&polygons, 4, ContainmentMode::Center).expect("cells");
+    /// assert_eq!(cells, vec![cell]);
+    /// ```
+    pub fn from_multipolygon(
+        polygons: &MultiPolygon,
+        res: u8,
+        mode: ContainmentMode,
+    ) -> Result<Vec<Self>, QuadbinError> {
+        if res > MAX_RESOLUTION {
+            return Err(QuadbinError::InvalidResolution(res));
+        }
+
+        fill(&Areal::from(polygons), res, mode)
+    }
+}
+
+// Internal functions ------------------------------------------------
+/// Relation between a cell and an areal geometry.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) enum Relation {
+    /// Interiors do not intersect.
+    Disjoint,
+    /// Interiors intersect, but the cell is not covered.
+    Partial,
+    /// The cell is fully covered by the geometry.
+    Covered,
+}
+
+/// Convert cell into a [geo::Rect] in degrees.
+pub(crate) fn cell_to_rect(cell: &Cell) -> Rect {
+    let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+    Rect::new(coord! { x: xmin, y: ymin }, coord! { x: xmax, y: ymax })
+}
+
+/// A prepared areal geometry.
+pub(crate) enum Areal<'a> {
+    Polygon(PreparedGeometry<'a, &'a Polygon>),
+    MultiPolygon(PreparedGeometry<'a, &'a MultiPolygon>),
+}
+
+impl<'a> From<&'a Polygon> for Areal<'a> {
+    fn from(polygon: &'a Polygon) -> Self {
+        Areal::Polygon(PreparedGeometry::from(polygon))
+    }
+}
+
+impl<'a> From<&'a MultiPolygon> for Areal<'a> {
+    fn from(polygons: &'a MultiPolygon) -> Self {
+        Areal::MultiPolygon(PreparedGeometry::from(polygons))
+    }
+}
+
+impl Areal<'_> {
+    pub(crate) fn bounding_rect(&self) -> Option<Rect> {
+        match self {
+            Areal::Polygon(prepared) => prepared.geometry().bounding_rect(),
+            Areal::MultiPolygon(prepared) => prepared.geometry().bounding_rect(),
+        }
+    }
+
+    fn relate(&self, rect: &Rect) -> IntersectionMatrix {
+        match self {
+            Areal::Polygon(prepared) => prepared.relate(rect),
+            Areal::MultiPolygon(prepared) => prepared.relate(rect),
+        }
+    }
+
+    fn intersects(&self, point: &Point) -> bool {
+        match self {
+            Areal::Polygon(prepared) => prepared.geometry().intersects(point),
+            Areal::MultiPolygon(prepared) => prepared.geometry().intersects(point),
+        }
+    }
+}
+
+/// Compute the cells covering an areal geometry, in ascending order.
+pub(crate) fn fill(
+    areal: &Areal,
+    res: u8,
+    mode: ContainmentMode,
+) -> Result<Vec<Cell>, QuadbinError> {
+    let mut cells = Vec::new();
+    let Some(bounds) = areal.bounding_rect() else {
+        return Ok(cells);
+    };
+
+    let root = Tile::new(0, 0, 0).to_cell()?;
+    polyfill(areal, &bounds, root, res, mode, &mut cells);
+
+    Ok(cells)
+}
+
+/// Find how a cell relates to a prepared areal geometry.
+pub(crate) fn cell_relation(areal: &Areal, bounds: &Rect, cell: &Cell) -> Relation {
+    let rect = cell_to_rect(cell);
+
+    // Cheap bounding box check before building the topology graph
+    if !bounds.intersects(&rect) {
+        return Relation::Disjoint;
+    }
+
+    let matrix = areal.relate(&rect);
+    if matrix.is_covers() {
+        Relation::Covered
+    } else if matrix.get(CoordPos::Inside, CoordPos::Inside) != Dimensions::Empty {
+        Relation::Partial
+    } else {
+        Relation::Disjoint
+    }
+}
+
+/// Recursively collect cells of the polyfill, starting from `cell`.
+fn polyfill(
+    areal: &Areal,
+    bounds: &Rect,
+    cell: Cell,
+    res: u8,
+    mode: ContainmentMode,
+    cells: &mut Vec<Cell>,
+) {
+    let resolution = cell.resolution();
+
+    match cell_relation(areal, bounds, &cell) {
+        Relation::Disjoint => {}
+        Relation::Covered if resolution == res => cells.push(cell),
+        Relation::Covered => {
+            let kids = cell.children(res).expect("valid resolution");
+            cells.extend(kids.map(|kid| kid.expect("cell index")));
+        }
+        Relation::Partial if resolution == res => {
+            let inside = match mode {
+                ContainmentMode::Center => {
+                    let [lat, lng] = cell.to_point();
+                    areal.intersects(&Point::new(lng, lat))
+                }
+                ContainmentMode::Contains => false,
+                ContainmentMode::Intersects => true,
+            };
+
+            if inside {
+                cells.push(cell);
+            }
+        }
+        Relation::Partial => {
+            let kids = cell.children(resolution + 1).expect("valid resolution");
+            for kid in kids {
+                polyfill(areal, bounds, kid.expect("cell index"), res, mode, cells);
+            }
+        }
+    }
+}
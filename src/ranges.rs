@@ -0,0 +1,177 @@
+use crate::Cell;
+use crate::errors::QuadbinError;
+use crate::tiles::Tile;
+use crate::utils::*;
+use core::ops::RangeInclusive;
+
+/// Range decomposition.
+impl Cell {
+    /// Decompose a bounding box into contiguous ranges of cell indexes at a
+    /// specific resolution.
+    ///
+    /// Cells at a given resolution are numbered along a Z-order curve, so
+    /// the cells intersecting a box form a few runs of consecutive
+    /// indexes. Every cell intersecting the box falls in one of the ranges,
+    /// and every cell of the resolution within the ranges intersects the
+    /// box. Ranges are sorted, disjoint and as few as possible, making them
+    /// suitable for range scans over storage sorted by cell index.
+    ///
+    /// Bounds are in degrees, and boxes with `xmin > xmax` cross the
+    /// antimeridian. Cells only touching the box along an edge are left
+    /// out. See [Cell::bbox_ranges_with] to limit the number of ranges.
+    ///
+    /// As with [Cell::descendant_range], not every integer in a range is a
+    /// valid cell index.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// // A cell's bounding box is a single range
+    /// let cell = Cell::new(5209574053332910079);
+    /// let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+    /// let ranges = Cell::bbox_ranges(xmin, ymin, xmax, ymax, 8).expect("ranges");
+    /// assert_eq!(ranges, vec![cell.descendant_range(8).expect("range")]);
+    /// ```
+    pub fn bbox_ranges(
+        xmin: f64,
+        ymin: f64,
+        xmax: f64,
+        ymax: f64,
+        res: u8,
+    ) -> Result<Vec<RangeInclusive<u64>>, QuadbinError> {
+        let Some(tiles) = bbox_to_tiles(xmin, ymin, xmax, ymax, res)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut ranges = Vec::new();
+        decompose(&tiles, Tile::new(0, 0, 0), res, &mut ranges)?;
+
+        Ok(ranges)
+    }
+
+    /// Decompose a bounding box into at most `max_ranges` ranges of cell
+    /// indexes at a specific resolution.
+    ///
+    /// Starting from the exact decomposition of [Cell::bbox_ranges], the
+    /// ranges separated by the smallest gaps are merged until at most
+    /// `max_ranges` remain. Ranges then still cover every cell
+    /// intersecting the box, at the cost of some false positives, which
+    /// callers should filter out. A `max_ranges` of 0 is treated as 1.
+    ///
+    /// # Errors
+    /// [QuadbinError::InvalidResolution] if `res` is greater than 26.
+    ///
+    /// # Example
+    /// ```
+    /// use qbin::Cell;
+    ///
+    /// let exact = Cell::bbox_ranges(-3.9, 40.3, -3.5, 40.6, 14).expect("ranges");
+    /// let coarse = Cell::bbox_ranges_with(-3.9, 40.3, -3.5, 40.6, 14, 4).expect("ranges");
+    /// assert!(exact.len() > 4);
+    /// assert_eq!(coarse.len(), 4);
+    /// assert_eq!(coarse[0].start(), exact[0].start());
+    /// assert_eq!(coarse[3].end(), exact[exact.len() - 1].end());
+    /// ```
+    pub fn bbox_ranges_with(
+        xmin: f64,
+        ymin: f64,
+        xmax: f64,
+        ymax: f64,
+        res: u8,
+        max_ranges: usize,
+    ) -> Result<Vec<RangeInclusive<u64>>, QuadbinError> {
+        let ranges = Cell::bbox_ranges(xmin, ymin, xmax, ymax, res)?;
+        Ok(coarsen(ranges, max_ranges.max(1)))
+    }
+}
+
+// Internal functions ------------------------------------------------
+/// Recursively collect ranges of the cells intersecting the box, starting
+/// from `tile`.
+fn decompose(
+    tiles: &BboxTiles,
+    tile: Tile,
+    res: u8,
+    ranges: &mut Vec<RangeInclusive<u64>>,
+) -> Result<(), QuadbinError> {
+    // Span of the tile's descendants at the target resolution
+    let shift = res - tile.z;
+    let (x0, x1) = (tile.x << shift, ((tile.x + 1) << shift) - 1);
+    let (y0, y1) = (tile.y << shift, ((tile.y + 1) << shift) - 1);
+
+    let (ymin, ymax) = tiles.rows;
+    if y1 < ymin || y0 > ymax {
+        return Ok(());
+    }
+    if !tiles
+        .columns
+        .iter()
+        .any(|&(xmin, xmax)| x0 <= xmax && x1 >= xmin)
+    {
+        return Ok(());
+    }
+
+    let rows_covered = ymin <= y0 && y1 <= ymax;
+    let columns_covered = tiles
+        .columns
+        .iter()
+        .any(|&(xmin, xmax)| xmin <= x0 && x1 <= xmax);
+
+    if rows_covered && columns_covered {
+        let range = tile.to_cell()?.descendant_range(res)?;
+        let step = 1_u64 << (52 - 2 * res as u64);
+
+        // Tiles are visited in index order, extend the last range if
+        // contiguous
+        match ranges.last_mut() {
+            Some(last) if *last.end() + step == *range.start() => {
+                *last = *last.start()..=*range.end();
+            }
+            _ => ranges.push(range),
+        }
+        return Ok(());
+    }
+
+    // Children in Morton order
+    for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+        let child = Tile::new(2 * tile.x + dx, 2 * tile.y + dy, tile.z + 1);
+        decompose(tiles, child, res, ranges)?;
+    }
+
+    Ok(())
+}
+
+/// Merge ranges separated by the smallest gaps, until at most
+/// `max_ranges` remain.
+fn coarsen(ranges: Vec<RangeInclusive<u64>>, max_ranges: usize) -> Vec<RangeInclusive<u64>> {
+    if ranges.len() <= max_ranges {
+        return ranges;
+    }
+
+    // Gaps after each range, smallest first, ties broken by position
+    let mut gaps = ranges
+        .windows(2)
+        .enumerate()
+        .map(|(i, pair)| (pair[1].start() - pair[0].end(), i))
+        .collect::<Vec<_>>();
+    gaps.sort_unstable();
+
+    let mut merged = vec![false; ranges.len()];
+    for &(_, i) in &gaps[..ranges.len() - max_ranges] {
+        merged[i] = true;
+    }
+
+    let mut coarse: Vec<RangeInclusive<u64>> = Vec::with_capacity(max_ranges);
+    for (i, range) in ranges.into_iter().enumerate() {
+        match coarse.last_mut() {
+            Some(last) if merged[i - 1] => *last = *last.start()..=*range.end(),
+            _ => coarse.push(range),
+        }
+    }
+
+    coarse
+}
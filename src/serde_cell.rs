@@ -0,0 +1,87 @@
+//! Alternative [serde] representations of [Cell].
+//!
+//! By default, cells are serialized as their `u64` index. Yet JSON
+//! numbers are read as doubles by JavaScript and many other consumers,
+//! which cannot represent every 64-bit integer. These modules serialize
+//! cells as strings instead, to be used with the `#[serde(with = "...")]`
+//! attribute.
+//!
+//! Both modules accept decimal and hexadecimal strings on
+//! deserialization, see [Cell]'s [FromStr](std::str::FromStr)
+//! implementation.
+//!
+//! # Example
+//! ```
+//! use qbin::Cell;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Feature {
+//!     #[serde(with = "qbin::serde_cell::hex")]
+//!     cell: Cell,
+//!     #[serde(with = "qbin::serde_cell::string")]
+//!     parent: Cell,
+//! }
+//!
+//! let cell = Cell::new(5209574053332910079);
+//! let feature = Feature { cell, parent: cell.parent(0).expect("cell index") };
+//! let json = serde_json::to_string(&feature).expect("json");
+//! assert_eq!(json, r#"{"cell":"484c1fffffffffff","parent":"5192650370358181887"}"#);
+//! ```
+
+use crate::Cell;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+use std::borrow::Cow;
+
+/// Parse a cell from a string, validating its index.
+fn deserialize_str<'de, D>(deserializer: D) -> Result<Cell, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Cow::<str>::deserialize(deserializer)?;
+    s.parse().map_err(D::Error::custom)
+}
+
+/// Serialize cells as lowercase hexadecimal strings, e.g.
+/// `"484c1fffffffffff"`.
+pub mod hex {
+    use super::*;
+
+    /// Serialize a cell as an hexadecimal string.
+    pub fn serialize<S>(cell: &Cell, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&cell.to_hex())
+    }
+
+    /// Deserialize a cell from a string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Cell, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_str(deserializer)
+    }
+}
+
+/// Serialize cells as decimal strings, e.g. `"5209574053332910079"`.
+pub mod string {
+    use super::*;
+
+    /// Serialize a cell as a decimal string.
+    pub fn serialize<S>(cell: &Cell, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(cell)
+    }
+
+    /// Deserialize a cell from a string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Cell, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_str(deserializer)
+    }
+}
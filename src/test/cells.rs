@@ -1,7 +1,9 @@
 use crate::cells::*;
-use crate::directions::Direction;
+use crate::directions::{Direction, Neighborhood};
 use crate::tiles::*;
+use crate::{Adjacency, EarthModel, Topology};
 use approx::assert_relative_eq;
+use std::f64::consts::PI;
 
 // Constants to save some typing
 const UP: Direction = Direction::Up;
@@ -90,6 +92,99 @@ fn test_cell_to_bbox() {
     }
 }
 
+// Locate points within their cells and back
+#[test]
+fn test_cell_locate_point() {
+    let cases = [
+        (40.4168, -3.7038),
+        (-11.178401873711776, 33.75),
+        (-41.28303675124842, 174.77727344223067),
+        (37.774929, -122.419418),
+    ];
+
+    for (lat, lng) in cases {
+        for res in [0, 4, 10, 18, 26] {
+            let (cell, fx, fy) = Cell::locate_point(lat, lng, res).expect("cell index");
+            assert_eq!(cell, Cell::from_point(lat, lng, res).expect("cell index"));
+            assert!((0.0..1.0).contains(&fx) && (0.0..1.0).contains(&fy));
+
+            let [y, x] = cell.point_at_offset(fx, fy).expect("offset");
+            assert_relative_eq!(y, lat, epsilon = 1e-9);
+            assert_relative_eq!(x, lng, epsilon = 1e-9);
+        }
+    }
+
+    // Offsets grow eastwards and southwards
+    let (_, fx, fy) = Cell::locate_point(40.0, -3.9, 8).expect("cell index");
+    let (_, fx_east, fy_south) = Cell::locate_point(39.99, -3.89, 8).expect("cell index");
+    assert!(fx_east > fx && fy_south > fy);
+
+    // Edges belong to the eastern and southern cell
+    let cell = Cell::new(5209574053332910079);
+    let [west, _, _, north] = cell.to_bbox();
+    assert_eq!(Cell::locate_point(north, west, 4), Ok((cell, 0.0, 0.0)));
+
+    // Corners of the cell
+    let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+    assert_eq!(cell.point_at_offset(0.0, 0.0), Ok([ymax, xmin]));
+    assert_eq!(cell.point_at_offset(1.0, 1.0), Ok([ymin, xmax]));
+    assert_eq!(cell.point_at_offset(0.5, 0.5), Ok(cell.to_point()));
+}
+
+// Convert Web Mercator coordinates to cells and back
+#[test]
+fn test_cell_mercator() {
+    let extent = 20037508.342789244;
+    let cell = Cell::new(5209574053332910079);
+    assert_eq!(
+        cell.to_mercator_bbox(),
+        [extent / 8.0, -extent / 8.0, extent / 4.0, 0.0]
+    );
+    assert_eq!(cell.mercator_center(), [extent * 0.1875, -extent / 16.0]);
+
+    // Same cells as from geographic coordinates
+    let cases: [(f64, f64); 4] = [
+        (-3.7038, 40.4168),
+        (33.75, -11.178401873711776),
+        (174.77727344223067, -41.28303675124842),
+        (-122.419418, 37.774929),
+    ];
+    for (lng, lat) in cases {
+        let x = extent * lng / 180.0;
+        let y = extent / PI * f64::tan(PI / 4.0 + lat.to_radians() / 2.0).ln();
+        for res in [0, 4, 10, 18] {
+            let cell = Cell::from_mercator(x, y, res).expect("cell index");
+            assert_eq!(cell, Cell::from_point(lat, lng, res).expect("cell index"));
+            assert_eq!(
+                Cell::from_mercator(cell.mercator_center()[0], cell.mercator_center()[1], res),
+                Ok(cell)
+            );
+        }
+    }
+
+    // Bounding boxes match the geographic ones
+    let [xmin, ymin, xmax, ymax] = Cell::new(5309133744805926483).to_mercator_bbox();
+    let [west, south, east, north] = Cell::new(5309133744805926483).to_bbox();
+    assert_relative_eq!(xmin / extent * 180.0, west, epsilon = 1e-9);
+    assert_relative_eq!(xmax / extent * 180.0, east, epsilon = 1e-9);
+    assert!(ymin < ymax && south < north);
+
+    // Eastern edge wraps, northings beyond the extent are clipped
+    assert_eq!(Cell::from_mercator(extent, 0.0, 2), Cell::from_zxy(2, 0, 2));
+    assert_eq!(
+        Cell::from_mercator(-extent, 0.0, 2),
+        Cell::from_zxy(2, 0, 2)
+    );
+    assert_eq!(
+        Cell::from_mercator(0.0, 2.0 * extent, 2),
+        Cell::from_zxy(2, 2, 0)
+    );
+    assert_eq!(
+        Cell::from_mercator(0.0, -2.0 * extent, 2),
+        Cell::from_zxy(2, 2, 3)
+    );
+}
+
 // Convert quadbin cell back to coords
 #[test]
 fn test_cell_to_point() {
@@ -130,10 +225,55 @@ fn test_cell_to_parent() {
 // Estimate cell area
 #[test]
 fn test_cell_area() {
-    let area = Cell::try_from(5209574053332910079_u64)
-        .expect("cell index")
-        .area_m2();
-    assert_relative_eq!(area, 6023040823252.664, epsilon = 1e-2);
+    let cell = Cell::try_from(5209574053332910079_u64).expect("cell index");
+    assert_relative_eq!(cell.area_m2(), 5933441091285.86, max_relative = 1e-10);
+    assert_eq!(cell.area_m2(), cell.area_m2_with(EarthModel::Wgs84));
+
+    // Spherical areas of children add up to their parent's
+    let kids = cell.children(7).unwrap().map(|kid| kid.unwrap());
+    let total = kids
+        .map(|kid| kid.area_m2_with(EarthModel::Sphere))
+        .sum::<f64>();
+    assert_relative_eq!(
+        total,
+        cell.area_m2_with(EarthModel::Sphere),
+        max_relative = 1e-10
+    );
+}
+
+// Measure cell edges
+#[test]
+fn test_cell_edge_lengths() {
+    // North-eastern quadrant at resolution 1
+    let cell = Cell::from_point(10.0, 10.0, 1).expect("cell index");
+    let south = cell.edge_length_m(Direction::Down).expect("edge");
+    let east = cell.edge_length_m(Direction::Right).expect("edge");
+
+    // Southern edge is half the equator
+    assert_relative_eq!(south, 20037508.342789244, max_relative = 1e-12);
+    // Eastern edge is the meridian arc up to 85.0511°, a bit less than
+    // the 10001965.729 m quadrant
+    assert!(east > 9_440_000.0 && east < 10_001_965.729);
+    assert_eq!(cell.edge_length_m(Direction::Left), Some(east));
+    assert_eq!(cell.edge_length_m(Direction::DownLeft), None);
+    assert_eq!(cell.edge_length_m(Direction::Center), None);
+
+    let north = cell.edge_length_m(Direction::Up).expect("edge");
+    assert_relative_eq!(cell.perimeter_m(), north + south + 2.0 * east);
+
+    // Spherical meridian arcs are proportional to latitude span
+    let [_, ymin, _, ymax] = cell.to_bbox();
+    assert_relative_eq!(
+        cell.edge_length_m_with(Direction::Right, EarthModel::Sphere)
+            .expect("edge"),
+        6_371_007.180_918_475 * (ymax - ymin).to_radians(),
+        max_relative = 1e-12
+    );
+
+    // Width at the center parallel lies between both horizontal edges
+    let (width, height) = cell.width_height_m();
+    assert!(north < width && width < south);
+    assert_eq!(height, east);
 }
 
 // Find cell's neighbors
@@ -307,3 +447,328 @@ fn test_cell_children_six() {
         assert_eq!(cell.get(), truth[i]);
     }
 }
+
+// Compact and uncompact cell sets
+#[test]
+fn test_cell_compact() {
+    let parent = Cell::new(5209574053332910079);
+    let neighbor = parent.neighbor(RIGHT).expect("cell index");
+
+    // A full set of grandchildren and three out of four children
+    let mut cells = parent
+        .children(6)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("cell index");
+    let kids = neighbor
+        .children(5)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("cell index");
+    cells.extend_from_slice(&kids[1..]);
+
+    let mut truth = vec![parent, kids[1], kids[2], kids[3]];
+    truth.sort_by_key(|cell| cell.get());
+    assert_eq!(Cell::compact(cells.clone()), Ok(truth.clone()));
+
+    // Compaction is lossless
+    let mut restored = Cell::uncompact(truth, 6).expect("cells");
+    let mut expanded = Cell::uncompact(cells, 6).expect("cells");
+    restored.sort_by_key(|cell| cell.get());
+    expanded.sort_by_key(|cell| cell.get());
+    assert_eq!(restored, expanded);
+
+    // The whole world compacts into the root cell
+    let root = Cell::new(5192650370358181887);
+    let world = root
+        .children(3)
+        .unwrap()
+        .map(|cell| cell.expect("cell index"));
+    assert_eq!(Cell::compact(world), Ok(vec![root]));
+    assert_eq!(Cell::compact([]), Ok(vec![]));
+}
+
+#[test]
+fn test_cell_uncompact() {
+    let parent = Cell::new(5209574053332910079);
+    let cells = [parent, Cell::new(5214064458820747263)];
+
+    let expanded = Cell::uncompact(cells, 5).expect("cells");
+    assert_eq!(expanded.len(), 5);
+    assert!(expanded.iter().all(|cell| cell.resolution() == 5));
+    assert_eq!(Cell::uncompact([parent], 4), Ok(vec![parent]));
+}
+
+// List all Cell's Moore neighbors
+#[test]
+fn test_cell_neighbors8() {
+    let cell = Cell::new(5209574053332910079);
+    let neighbors = cell.neighbors8();
+
+    assert_eq!(neighbors[..4], cell.neighbors()[..]);
+    assert_eq!(
+        neighbors[4],
+        cell.neighbor(UP).and_then(|up| up.neighbor(RIGHT))
+    );
+    assert_eq!(
+        neighbors[7],
+        cell.neighbor(DOWN).and_then(|down| down.neighbor(LEFT))
+    );
+    assert_eq!(cell.neighbor(Direction::Center), Some(cell));
+
+    // Corner of the grid at resolution 1
+    let corner = Cell::new(5193776270265024511).neighbors8();
+    assert_eq!(corner.iter().flatten().count(), 3);
+}
+
+// Wrap neighbors across the antimeridian
+#[test]
+fn test_cell_neighbors_cylindrical() {
+    let west = Cell::from_point(64.8, -179.9, 9).expect("cell index");
+    let east = Cell::from_point(64.8, 179.9, 9).expect("cell index");
+
+    assert_eq!(west.neighbor(LEFT), None);
+    assert_eq!(west.neighbor_with(LEFT, Topology::Cylindrical), Some(east));
+    assert_eq!(east.neighbor_with(RIGHT, Topology::Cylindrical), Some(west));
+    assert_eq!(
+        east.neighbor_with(Direction::UpRight, Topology::Cylindrical),
+        west.neighbor(UP)
+    );
+
+    // Default topology is unchanged
+    assert_eq!(west.neighbors_with(Topology::Bounded), west.neighbors());
+    assert_eq!(west.neighbors().iter().flatten().count(), 3);
+    assert_eq!(
+        west.neighbors_with(Topology::Cylindrical)
+            .iter()
+            .flatten()
+            .count(),
+        4
+    );
+    assert_eq!(
+        west.neighbors8_with(Topology::Cylindrical)
+            .iter()
+            .flatten()
+            .count(),
+        8
+    );
+
+    // Northern edge never wraps, both horizontal neighbors are the same
+    // cell at resolution 1
+    let corner = Cell::new(5193776270265024511);
+    let neighbors = corner.neighbors8_with(Topology::Cylindrical);
+    assert_eq!(neighbors.iter().flatten().count(), 5);
+    assert_eq!(neighbors[1], neighbors[2]);
+
+    // No neighbors at resolution 0
+    let root = Cell::new(5192650370358181887);
+    assert_eq!(root.neighbors_with(Topology::Cylindrical), [None; 4]);
+}
+
+// Detect direction to a neighbor
+#[test]
+fn test_cell_direction_to_neighbor() {
+    let cells = [
+        Cell::new(5209574053332910079),
+        Cell::new(5193776270265024511),
+        Cell::new(5309133744805926483),
+    ];
+
+    for cell in cells.iter() {
+        for direction in Direction::iter_neighborhood(Neighborhood::Moore) {
+            if let Some(neighbor) = cell.neighbor(direction) {
+                assert_eq!(cell.direction_to_neighbor(&neighbor), Some(direction));
+            }
+        }
+        assert_eq!(cell.direction_to_neighbor(cell), None);
+    }
+
+    // Not adjacent, or different resolutions
+    let cell = cells[0];
+    let far = cell
+        .neighbor(RIGHT)
+        .and_then(|c| c.neighbor(RIGHT))
+        .unwrap();
+    assert_eq!(cell.direction_to_neighbor(&far), None);
+    assert_eq!(cell.direction_to_neighbor(&cell.parent(3).unwrap()), None);
+}
+
+// Classify adjacency
+#[test]
+fn test_cell_adjacency() {
+    let cell = Cell::new(5209574053332910079);
+    let right = cell.neighbor(RIGHT).unwrap();
+    let corner = cell.neighbor(Direction::UpLeft).unwrap();
+    let far = right.neighbor(RIGHT).unwrap();
+
+    assert_eq!(cell.adjacency(&right), Adjacency::SharesEdge);
+    assert_eq!(cell.adjacency(&corner), Adjacency::SharesCorner);
+    assert_eq!(cell.adjacency(&far), Adjacency::NotAdjacent);
+
+    // Overlapping cells
+    assert_eq!(cell.adjacency(&cell), Adjacency::NotAdjacent);
+    assert_eq!(
+        cell.adjacency(&cell.parent(2).unwrap()),
+        Adjacency::NotAdjacent
+    );
+
+    // Mixed resolutions
+    let kids = right
+        .children(6)
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let edge = kids
+        .iter()
+        .filter(|kid| cell.adjacency(kid) == Adjacency::SharesEdge);
+    let touching = kids
+        .iter()
+        .filter(|kid| cell.adjacency(kid) != Adjacency::NotAdjacent);
+    assert_eq!(edge.count(), 4);
+    assert_eq!(touching.count(), 4);
+
+    let corner_kids = corner.children(6).unwrap().map(|kid| kid.unwrap());
+    let corners = corner_kids.filter(|kid| kid.adjacency(&cell) == Adjacency::SharesCorner);
+    assert_eq!(corners.count(), 1);
+}
+
+// Quadkey conversion
+#[test]
+fn test_cell_quadkey() {
+    let cases = [
+        (5192650370358181887_u64, ""),
+        (5193776270265024511_u64, "0"),
+        (5197153969985552383_u64, "3"),
+        (5201094619659501567_u64, "31"),
+        (5209574053332910079_u64, "3001"),
+        (5309133744805926483_u64, "31311100030030030211121103"),
+    ];
+
+    for (cell, quadkey) in cases.iter() {
+        assert_eq!(Cell::new(*cell).to_quadkey(), *quadkey);
+        assert_eq!(Cell::from_quadkey(quadkey), Ok(Cell::new(*cell)));
+    }
+
+    // Tiles and quadkeys agree
+    let tile = Tile::new(1023, 2412, 23);
+    let quadkey = tile.to_cell().unwrap().to_quadkey();
+    assert_eq!(quadkey.len(), 23);
+    assert_eq!(Cell::from_quadkey(&quadkey).unwrap().to_tile(), tile);
+}
+
+// Slippy map tile coordinates
+#[test]
+fn test_cell_zxy() {
+    let cases = [
+        (9_u32, 8_u32, 4_u8, 5209574053332910079_u64),
+        (0_u32, 0_u32, 0_u8, 5192650370358181887_u64),
+        (1_u32, 2_u32, 3_u8, 5202361257054699519_u64),
+        (1023_u32, 2412_u32, 23_u8, 5291729562728627583_u64),
+    ];
+
+    for (x, y, z, cell) in cases.iter() {
+        let cell = Cell::new(*cell);
+        assert_eq!(cell.to_zxy(), (*z, *x, *y));
+        assert_eq!(Cell::from_zxy(*z, *x, *y), Ok(cell));
+
+        // TMS rows are flipped
+        let tms_y = (1 << z) - 1 - y;
+        assert_eq!(cell.to_tms(), (*z, *x, tms_y));
+        assert_eq!(Cell::from_tms(*z, *x, tms_y), Ok(cell));
+    }
+
+    // Last tile of the highest resolution
+    let last = (1 << 26) - 1;
+    let cell = Cell::from_zxy(26, last, last).expect("cell index");
+    assert_eq!(cell.to_zxy(), (26, last, last));
+    assert_eq!(cell.to_tms(), (26, last, 0));
+}
+
+// Parse cells from strings
+#[test]
+fn test_cell_from_str() {
+    let cell = Cell::new(5209574053332910079);
+
+    assert_eq!(cell.to_string().parse::<Cell>(), Ok(cell));
+    assert_eq!(cell.to_hex().parse::<Cell>(), Ok(cell));
+    assert_eq!(format!("{:#x}", cell).parse::<Cell>(), Ok(cell));
+    assert_eq!(format!("{:X}", cell).parse::<Cell>(), Ok(cell));
+    assert_eq!(format!("{:#X}", cell), "0x484C1FFFFFFFFFFF");
+
+    // Finest resolution, no trailing bits
+    let fine = Cell::from_point(-41.2865, 174.7762, 26).expect("cell index");
+    assert_eq!(fine.to_hex().parse::<Cell>(), Ok(fine));
+    assert_eq!(fine.to_string().parse::<Cell>(), Ok(fine));
+}
+
+// Find descendants by index range
+#[test]
+fn test_cell_descendant_range() {
+    let cell = Cell::new(5209574053332910079);
+    for res in 5..=9 {
+        let kids = cell
+            .children(res)
+            .expect("children")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("cell index");
+        let range = cell.descendant_range(res).expect("range");
+
+        assert_eq!(cell.min_child(res), Ok(kids[0]));
+        assert_eq!(cell.max_child(res), Ok(kids[kids.len() - 1]));
+        assert!(kids.iter().all(|kid| range.contains(&kid.get())));
+
+        // Cells just outside the range are not descendants
+        let step = 1 << (52 - 2 * res as u64);
+        let before = Cell::try_from(range.start() - step);
+        let after = Cell::try_from(range.end() + step);
+        assert!(before.is_ok_and(|c| c.parent(4) != Ok(cell)));
+        assert!(after.is_ok_and(|c| c.parent(4) != Ok(cell)));
+    }
+
+    // Same resolution is the cell itself
+    assert_eq!(cell.descendant_range(4), Ok(cell.get()..=cell.get()));
+    assert_eq!(cell.min_child(4), Ok(cell));
+    assert_eq!(cell.max_child(4), Ok(cell));
+
+    // Root covers every index at a resolution
+    let root = Cell::new(5192650370358181887);
+    let nw = Cell::from_point(85.0, -179.9, 26).expect("cell index");
+    let se = Cell::from_point(-85.0, 179.9, 26).expect("cell index");
+    let range = root.descendant_range(26).expect("range");
+    assert_eq!(
+        range,
+        root.min_child(26).expect("cell index").get()
+            ..=root.max_child(26).expect("cell index").get()
+    );
+    assert!(range.contains(&nw.get()) && range.contains(&se.get()));
+}
+
+// Binary search descendants in a sorted slice
+#[test]
+fn test_cell_descendants_in() {
+    let cell = Cell::new(5209574053332910079);
+    let neighbor = cell.neighbor(Direction::Right).expect("cell index");
+
+    // Mixed resolutions, inside and outside the cell
+    let mut cells = Vec::new();
+    for ancestor in [cell, neighbor] {
+        for res in [4, 6, 7] {
+            cells.extend(Cell::uncompact([ancestor], res).expect("cells"));
+        }
+    }
+    cells.sort();
+
+    for res in [4, 6, 7] {
+        let found = cell.descendants_in(&cells, res).expect("descendants");
+        let truth = cells
+            .iter()
+            .filter(|c| c.resolution() == res && (c == &&cell || c.parent(4) == Ok(cell)))
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(found, &truth[..]);
+        assert_eq!(found.len(), 1 << (2 * (res - 4)));
+    }
+
+    assert_eq!(cell.descendants_in(&cells, 5), Ok(&[][..]));
+    assert_eq!(cell.descendants_in(&[], 6), Ok(&[][..]));
+}
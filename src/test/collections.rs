@@ -0,0 +1,71 @@
+use crate::{BuildCellHasher, Cell, CellMap, CellSet};
+use std::collections::{BTreeSet, HashSet};
+use std::hash::BuildHasher;
+
+#[test]
+fn test_cell_ordering() {
+    let cell = Cell::new(5209574053332910079);
+    let kids = cell
+        .children(7)
+        .expect("children")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("cell index");
+
+    // Children are yielded in index order
+    let sorted = kids.iter().copied().collect::<BTreeSet<_>>();
+    assert!(sorted.iter().copied().eq(kids.iter().copied()));
+    assert!(
+        kids.windows(2)
+            .all(|w| (w[0] < w[1]) == (w[0].get() < w[1].get()))
+    );
+}
+
+#[test]
+fn test_cell_map() {
+    let cell = Cell::new(5209574053332910079);
+    let mut map = CellMap::default();
+    for kid in cell.children(10).expect("children") {
+        let kid = kid.expect("cell index");
+        *map.entry(kid.parent(8).expect("cell index")).or_insert(0) += 1;
+    }
+
+    assert_eq!(map.len(), 256);
+    assert!(map.values().all(|count| *count == 16));
+
+    let set = map.keys().copied().collect::<CellSet>();
+    assert!(
+        set.contains(
+            &cell
+                .children(8)
+                .expect("children")
+                .next()
+                .expect("child")
+                .expect("cell index")
+        )
+    );
+    assert!(!set.contains(&cell));
+}
+
+#[test]
+fn test_cell_hasher_spread() {
+    // Cells at the same resolution only differ in a few low bits, their
+    // hashes must still spread over the top and bottom bits
+    let build = BuildCellHasher::default();
+    let cell = Cell::new(5209574053332910079);
+    let hashes = cell
+        .children(9)
+        .expect("children")
+        .map(|kid| build.hash_one(kid.expect("cell index")))
+        .collect::<Vec<_>>();
+
+    let low = hashes.iter().map(|h| h & 0x3ff).collect::<HashSet<_>>();
+    let high = hashes.iter().map(|h| h >> 57).collect::<HashSet<_>>();
+    assert!(low.len() > 350);
+    assert_eq!(high.len(), 128);
+
+    // Hashing is deterministic
+    assert_eq!(
+        build.hash_one(cell),
+        BuildCellHasher::default().hash_one(cell)
+    );
+}
@@ -0,0 +1,133 @@
+use super::data::*;
+use crate::errors::*;
+use crate::{Cell, CellSet, ContainmentMode, RegionCoverer};
+use geo::{LineString, Polygon};
+
+// Expand a covering to a single resolution
+fn flatten(cells: &[Cell], res: u8) -> CellSet {
+    Cell::uncompact(cells.iter().copied(), res)
+        .expect("cells")
+        .into_iter()
+        .collect()
+}
+
+// Check resolutions and budget of a covering, and that its cells are
+// disjoint
+fn check_covering(coverer: &RegionCoverer, cells: &[Cell]) {
+    assert!(cells.len() <= coverer.max_cells());
+    assert!(cells.is_sorted());
+    assert!(cells.iter().all(|cell| {
+        (coverer.min_resolution()..=coverer.max_resolution()).contains(&cell.resolution())
+    }));
+    assert!(Cell::compact(cells.iter().copied()).is_ok());
+}
+
+#[test]
+fn test_region_coverer_new() {
+    let coverer = RegionCoverer::new(2, 12, 50).expect("coverer");
+    assert_eq!(coverer.min_resolution(), 2);
+    assert_eq!(coverer.max_resolution(), 12);
+    assert_eq!(coverer.max_cells(), 50);
+
+    assert!(RegionCoverer::new(26, 26, 1).is_ok());
+    assert_eq!(
+        RegionCoverer::new(5, 27, 8),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+    assert_eq!(
+        RegionCoverer::new(12, 11, 8),
+        Err(QuadbinError::InvalidResolution(12))
+    );
+}
+
+#[test]
+fn test_exterior_covering() {
+    let polygon = polygon_2d_with_interior();
+    let intersecting =
+        Cell::from_polygon(&polygon, 10, ContainmentMode::Intersects).expect("cells");
+
+    for max_cells in [1, 8, 20, 100, 1000] {
+        let coverer = RegionCoverer::new(3, 10, max_cells).expect("coverer");
+        let cells = coverer.exterior_covering(&polygon);
+        check_covering(&coverer, &cells);
+
+        // Every cell intersecting the polygon is covered
+        let covered = flatten(&cells, 10);
+        assert!(intersecting.iter().all(|cell| covered.contains(cell)));
+    }
+
+    // A larger budget gives a tighter covering
+    let coarse = RegionCoverer::new(3, 10, 10).expect("coverer");
+    let fine = RegionCoverer::new(3, 10, 200).expect("coverer");
+    let coarse = flatten(&coarse.exterior_covering(&polygon), 10);
+    let fine = flatten(&fine.exterior_covering(&polygon), 10);
+    assert!(fine.len() < coarse.len());
+    assert!(fine.is_subset(&coarse));
+}
+
+#[test]
+fn test_interior_covering() {
+    let polygon = polygon_2d_with_interior();
+    let contained = Cell::from_polygon(&polygon, 10, ContainmentMode::Contains)
+        .expect("cells")
+        .into_iter()
+        .collect::<CellSet>();
+
+    for max_cells in [1, 8, 20, 100, 1000] {
+        let coverer = RegionCoverer::new(3, 10, max_cells).expect("coverer");
+        let cells = coverer.interior_covering(&polygon);
+        check_covering(&coverer, &cells);
+
+        // Every cell is inside the polygon
+        assert!(flatten(&cells, 10).is_subset(&contained));
+    }
+
+    // Coarser cells are kept in the interior
+    let coverer = RegionCoverer::new(3, 10, 100).expect("coverer");
+    let cells = coverer.interior_covering(&polygon);
+    assert!(cells.iter().any(|cell| cell.resolution() < 10));
+}
+
+#[test]
+fn test_covering_single_resolution() {
+    // With a single resolution and enough budget, coverings match the
+    // polyfill
+    let polygon = polygon_2d();
+    let coverer = RegionCoverer::new(8, 8, 10_000).expect("coverer");
+
+    assert_eq!(
+        Ok(coverer.exterior_covering(&polygon)),
+        Cell::from_polygon(&polygon, 8, ContainmentMode::Intersects)
+    );
+    assert_eq!(
+        Ok(coverer.interior_covering(&polygon)),
+        Cell::from_polygon(&polygon, 8, ContainmentMode::Contains)
+    );
+
+    // Unless the polygon needs more cells than the budget at `min_res`
+    let coverer = RegionCoverer::new(8, 12, 4).expect("coverer");
+    assert_eq!(
+        Ok(coverer.exterior_covering(&polygon)),
+        Cell::from_polygon(&polygon, 8, ContainmentMode::Intersects)
+    );
+}
+
+#[test]
+fn test_covering_cell() {
+    let cell = Cell::new(5209574053332910079);
+    let polygon = cell.to_polygon();
+
+    let coverer = RegionCoverer::new(0, 12, 1).expect("coverer");
+    assert_eq!(coverer.exterior_covering(&polygon), vec![cell]);
+    assert_eq!(coverer.interior_covering(&polygon), vec![cell]);
+
+    // Cells coarser than `min_res` are split
+    let coverer = RegionCoverer::new(6, 12, 1).expect("coverer");
+    let kids = Cell::uncompact([cell], 6).expect("cells");
+    assert_eq!(coverer.exterior_covering(&polygon), kids);
+    assert_eq!(coverer.interior_covering(&polygon), kids);
+
+    // Empty polygons have empty coverings
+    let empty = Polygon::new(LineString::new(vec![]), vec![]);
+    assert_eq!(coverer.exterior_covering(&empty), vec![]);
+}
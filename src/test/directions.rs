@@ -20,3 +20,44 @@ fn test_valid_direction() {
     assert_eq!(Direction::try_from(2).ok(), Some(Direction::Left));
     assert_eq!(Direction::try_from(3).ok(), Some(Direction::Down))
 }
+
+#[test]
+fn test_diagonal_direction() {
+    let dirs = [
+        (4, Direction::UpRight),
+        (5, Direction::UpLeft),
+        (6, Direction::DownRight),
+        (7, Direction::DownLeft),
+        (8, Direction::Center),
+    ];
+
+    for (val, dir) in dirs.iter() {
+        assert_eq!(u8::from(*dir), *val);
+        assert_eq!(Direction::try_from(*val).ok(), Some(*dir));
+    }
+
+    assert!(Direction::try_from(9).is_err());
+}
+
+#[test]
+fn test_direction_iter() {
+    let edges = Direction::iter().collect::<Vec<_>>();
+    assert_eq!(
+        edges,
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Left,
+            Direction::Down
+        ]
+    );
+    assert_eq!(
+        Direction::iter_neighborhood(Neighborhood::VonNeumann).collect::<Vec<_>>(),
+        edges
+    );
+
+    let moore = Direction::iter_neighborhood(Neighborhood::Moore).collect::<Vec<_>>();
+    assert_eq!(moore.len(), 8);
+    assert_eq!(moore[..4], edges[..]);
+    assert!(!moore.contains(&Direction::Center));
+}
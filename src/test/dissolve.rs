@@ -0,0 +1,192 @@
+use super::data::*;
+use crate::tiles::Tile;
+use crate::{Cell, CellSet, ContainmentMode, cells_to_multipolygon};
+use geo::{Area, BoundingRect, Validation, Winding};
+
+// Cells of a block of tiles, skipping some of them
+fn block(x: u32, y: u32, size: u32, res: u8, skip: &[(u32, u32)]) -> Vec<Cell> {
+    let mut cells = Vec::new();
+    for dy in 0..size {
+        for dx in 0..size {
+            if !skip.contains(&(dx, dy)) {
+                cells.push(
+                    Tile::new(x + dx, y + dy, res)
+                        .to_cell()
+                        .expect("cell index"),
+                );
+            }
+        }
+    }
+    cells
+}
+
+#[test]
+fn test_dissolve_children() {
+    let parent = Cell::new(5209574053332910079);
+    let kids = Cell::uncompact([parent], 9).expect("cells");
+
+    let outline = cells_to_multipolygon(kids);
+    assert_eq!(outline.0.len(), 1);
+    assert_eq!(outline.0[0].interiors().len(), 0);
+    assert_eq!(outline.0[0].exterior().0.len(), 5);
+    assert!(outline.0[0].exterior().is_ccw());
+
+    let rect = outline.bounding_rect().expect("bounds");
+    let [xmin, ymin, xmax, ymax] = parent.to_bbox();
+    assert_eq!(
+        [rect.min().x, rect.min().y, rect.max().x, rect.max().y],
+        [xmin, ymin, xmax, ymax]
+    );
+}
+
+#[test]
+fn test_dissolve_mixed_resolutions() {
+    // Three children and the grandchildren of the fourth one
+    let parent = Cell::new(5209574053332910079);
+    let mut kids = Cell::uncompact([parent], 5).expect("cells");
+    let last = kids.pop().expect("cell index");
+    kids.extend(Cell::uncompact([last], 7).expect("cells"));
+
+    let outline = cells_to_multipolygon(kids.clone());
+    assert_eq!(outline, cells_to_multipolygon([parent]));
+
+    // Ancestors take precedence over their descendants
+    kids.push(parent);
+    kids.push(parent);
+    assert_eq!(cells_to_multipolygon(kids), cells_to_multipolygon([parent]));
+
+    assert_eq!(cells_to_multipolygon([]).0.len(), 0);
+}
+
+#[test]
+fn test_dissolve_holes() {
+    // A ring of cells around a missing one
+    let outline = cells_to_multipolygon(block(500, 380, 3, 10, &[(1, 1)]));
+    assert_eq!(outline.0.len(), 1);
+    assert_eq!(outline.0[0].exterior().0.len(), 5);
+    assert_eq!(outline.0[0].interiors().len(), 1);
+    assert_eq!(outline.0[0].interiors()[0].0.len(), 5);
+    assert!(outline.0[0].interiors()[0].is_cw());
+    assert!(outline.is_valid());
+
+    // The hole touches the exterior at a corner
+    let outline = cells_to_multipolygon(block(500, 380, 3, 10, &[(1, 1), (2, 2)]));
+    assert_eq!(outline.0.len(), 1);
+    assert_eq!(outline.0[0].exterior().0.len(), 7);
+    assert_eq!(outline.0[0].interiors().len(), 1);
+    assert!(outline.is_valid());
+
+    // Two holes touching at a corner
+    let outline = cells_to_multipolygon(block(500, 380, 4, 10, &[(1, 1), (2, 2)]));
+    assert_eq!(outline.0.len(), 1);
+    assert_eq!(outline.0[0].interiors().len(), 2);
+    assert!(outline.is_valid());
+
+    // An island inside a hole
+    let hole = [
+        (1, 1),
+        (2, 1),
+        (3, 1),
+        (1, 2),
+        (3, 2),
+        (1, 3),
+        (2, 3),
+        (3, 3),
+    ];
+    let outline = cells_to_multipolygon(block(500, 380, 5, 10, &hole));
+    assert_eq!(outline.0.len(), 2);
+    assert_eq!(
+        outline
+            .0
+            .iter()
+            .map(|polygon| polygon.interiors().len())
+            .sum::<usize>(),
+        1
+    );
+    assert!(outline.is_valid());
+}
+
+#[test]
+fn test_dissolve_corners() {
+    // Two cells touching at a corner are separate polygons
+    let cells = [
+        Tile::new(10, 10, 5).to_cell().expect("cell index"),
+        Tile::new(11, 11, 5).to_cell().expect("cell index"),
+    ];
+    let outline = cells_to_multipolygon(cells);
+    assert_eq!(outline.0.len(), 2);
+    assert!(
+        outline
+            .0
+            .iter()
+            .all(|polygon| polygon.exterior().0.len() == 5)
+    );
+    assert!(outline.is_valid());
+}
+
+#[test]
+fn test_dissolve_checkerboard() {
+    // Cells of a checkerboard only touch at corners
+    let mut cells = Vec::new();
+    for y in 100..104 {
+        for x in (100..104).filter(|x| (x + y) % 2 == 0) {
+            cells.push(Tile::new(x, y, 8).to_cell().expect("cell index"));
+        }
+    }
+
+    let outline = cells_to_multipolygon(cells);
+    assert_eq!(outline.0.len(), 8);
+    assert!(
+        outline
+            .0
+            .iter()
+            .all(|polygon| { polygon.exterior().0.len() == 5 && polygon.interiors().is_empty() })
+    );
+    assert!(outline.is_valid());
+
+    // One polygon per edge-connected area on an irregular pattern
+    let skip = (0..10)
+        .flat_map(|y| (0..10).map(move |x| (x, y)))
+        .filter(|(x, y)| (x * 7 + y * 13) % 3 == 0)
+        .collect::<Vec<_>>();
+    let cells = block(100, 100, 10, 8, &skip);
+    let outline = cells_to_multipolygon(cells.clone());
+    assert_eq!(outline.0.len(), count_areas(&cells));
+    assert!(outline.is_valid());
+}
+
+// Count edge-connected areas in a set of cells
+fn count_areas(cells: &[Cell]) -> usize {
+    let mut seen = CellSet::default();
+    let mut areas = 0;
+    for &cell in cells {
+        if !seen.insert(cell) {
+            continue;
+        }
+        areas += 1;
+
+        let mut stack = vec![cell];
+        while let Some(current) = stack.pop() {
+            for neighbor in current.neighbors().into_iter().flatten() {
+                if cells.contains(&neighbor) && seen.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+    areas
+}
+
+#[test]
+fn test_dissolve_polyfill() {
+    let cells =
+        Cell::from_polygon(&polygon_2d_with_interior(), 9, ContainmentMode::Center).expect("cells");
+    let outline = cells_to_multipolygon(cells.clone());
+
+    let expected = cells
+        .iter()
+        .map(|cell| cell.to_polygon().unsigned_area())
+        .sum::<f64>();
+    assert!((outline.unsigned_area() - expected).abs() < 1e-9 * expected);
+    assert!(outline.is_valid());
+}
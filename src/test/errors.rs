@@ -51,3 +51,166 @@ fn test_invalid_child_res() {
     assert!(kids27.is_err());
     assert_eq!(kids27.err(), Some(QuadbinError::InvalidResolution(27)));
 }
+
+#[test]
+fn test_compact_overlapping_cells() {
+    let parent = Cell::new(5209574053332910079);
+    let child = Cell::new(5214064458820747263);
+
+    assert_eq!(
+        Cell::compact([child, parent]),
+        Err(QuadbinError::OverlappingCells(parent.get(), child.get()))
+    );
+    assert_eq!(
+        Cell::compact([parent, parent]),
+        Err(QuadbinError::OverlappingCells(parent.get(), parent.get()))
+    );
+}
+
+#[test]
+fn test_uncompact_invalid_resolution() {
+    let cell = Cell::new(5209574053332910079);
+
+    assert_eq!(
+        Cell::uncompact([cell], 3),
+        Err(QuadbinError::InvalidResolution(3))
+    );
+    assert_eq!(
+        Cell::uncompact([cell], 27),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_grid_distance_resolution_mismatch() {
+    let cell = Cell::new(5209574053332910079);
+    let parent = cell.parent(2).expect("cell index");
+
+    assert_eq!(
+        cell.grid_distance(&parent),
+        Err(QuadbinError::ResolutionMismatch(4, 2))
+    );
+}
+
+#[test]
+fn test_grid_path_resolution_mismatch() {
+    let cell = Cell::new(5209574053332910079);
+    let parent = cell.parent(3).expect("cell index");
+
+    assert_eq!(
+        parent.grid_path_cells(&cell),
+        Err(QuadbinError::ResolutionMismatch(3, 4))
+    );
+}
+
+#[test]
+fn test_invalid_quadkey() {
+    assert_eq!(
+        Cell::from_quadkey("0124"),
+        Err(QuadbinError::InvalidQuadkeyDigit('4'))
+    );
+    assert_eq!(
+        Cell::from_quadkey("01a"),
+        Err(QuadbinError::InvalidQuadkeyDigit('a'))
+    );
+    assert_eq!(
+        Cell::from_quadkey(&"0".repeat(27)),
+        Err(QuadbinError::QuadkeyTooLong(27))
+    );
+}
+
+#[test]
+fn test_invalid_zxy() {
+    assert_eq!(
+        Cell::from_zxy(27, 0, 0),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+    assert_eq!(
+        Cell::from_zxy(2, 4, 0),
+        Err(QuadbinError::InvalidTile(2, 4, 0))
+    );
+    assert_eq!(
+        Cell::from_zxy(0, 0, 1),
+        Err(QuadbinError::InvalidTile(0, 0, 1))
+    );
+    assert_eq!(
+        Cell::from_tms(3, 1, 8),
+        Err(QuadbinError::InvalidTile(3, 1, 8))
+    );
+    assert_eq!(
+        Cell::from_tms(2, 9, 1),
+        Err(QuadbinError::InvalidTile(2, 9, 1))
+    );
+    assert_eq!(
+        Cell::from_tms(30, 1, 1),
+        Err(QuadbinError::InvalidResolution(30))
+    );
+}
+
+#[test]
+fn test_invalid_mercator_resolution() {
+    assert_eq!(
+        Cell::from_mercator(0.0, 0.0, 27),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_invalid_offset() {
+    let cell = Cell::new(5209574053332910079);
+    assert_eq!(
+        cell.point_at_offset(1.5, 0.5),
+        Err(QuadbinError::InvalidOffset(1.5))
+    );
+    assert_eq!(
+        cell.point_at_offset(0.5, -0.1),
+        Err(QuadbinError::InvalidOffset(-0.1))
+    );
+    assert_eq!(
+        Cell::locate_point(0.0, 0.0, 27),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_invalid_cell_string() {
+    for s in [
+        "",
+        "0x",
+        "quadbin",
+        "-5209574053332910079",
+        "0x+484c1fffffffffff",
+        "484c1fffffffffff0",
+    ] {
+        assert_eq!(
+            s.parse::<Cell>(),
+            Err(QuadbinError::InvalidCellString(s.to_string()))
+        );
+    }
+
+    // Well-formed numbers that are not cell indexes
+    assert_eq!(
+        "5209574053332910078".parse::<Cell>(),
+        Err(QuadbinError::InvalidCell(Some(5209574053332910078)))
+    );
+    assert_eq!(
+        "0x484c1ffffffffffe".parse::<Cell>(),
+        Err(QuadbinError::InvalidCell(Some(0x484c1ffffffffffe)))
+    );
+}
+
+#[test]
+fn test_descendant_range_invalid_resolution() {
+    let cell = Cell::new(5209574053332910079);
+
+    assert_eq!(cell.min_child(3), Err(QuadbinError::InvalidResolution(3)));
+    assert_eq!(cell.max_child(27), Err(QuadbinError::InvalidResolution(27)));
+    assert_eq!(
+        cell.descendant_range(3),
+        Err(QuadbinError::InvalidResolution(3))
+    );
+    assert_eq!(
+        cell.descendants_in(&[cell], 27),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
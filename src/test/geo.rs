@@ -1,7 +1,10 @@
 use super::data::*;
-use crate::Cell;
 use crate::errors::*;
-use geo::{LineString, Polygon};
+use crate::{Cell, ContainmentMode, Direction, Interpolation, Neighborhood, Topology};
+use geo::{
+    Geometry, GeometryCollection, Line, LineString, MultiLineString, Polygon, Rect, Triangle,
+    coord, line_string,
+};
 
 #[test]
 fn test_quadbin_from_point() {
@@ -60,3 +63,288 @@ fn test_cell_to_polygon() {
 
     assert_eq!(qb_cell.to_polygon(), polygon)
 }
+
+#[test]
+fn test_cell_to_mercator_polygon() {
+    let extent = 20037508.342789244;
+    let bbox = [extent / 8.0, -extent / 8.0, extent / 4.0, 0.0];
+
+    let polygon = Polygon::new(
+        LineString::from(vec![
+            (bbox[0], bbox[1]), // bottom-left
+            (bbox[2], bbox[1]), // bottom-right
+            (bbox[2], bbox[3]), // top-right
+            (bbox[0], bbox[3]), // top-left
+            (bbox[0], bbox[1]), // back to bottom-left to close the loop
+        ]),
+        vec![],
+    );
+
+    let qb_cell = Cell::new(5209574053332910079);
+
+    assert_eq!(qb_cell.to_mercator_polygon(), polygon)
+}
+
+#[test]
+fn test_cells_from_rect() {
+    let cell = Cell::new(5209574053332910079);
+    let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+
+    // Corners given in any order
+    let rect = Rect::new(coord! { x: xmax, y: ymin }, coord! { x: xmin, y: ymax });
+    let cells = Cell::from_rect(rect, 6).expect("cells").collect::<Vec<_>>();
+    let kids = cell
+        .children(6)
+        .expect("children")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("cell index");
+
+    assert_eq!(cells.len(), 16);
+    assert!(kids.iter().all(|kid| cells.contains(kid)));
+}
+
+// Consecutive cells share an edge, possibly across the antimeridian
+fn is_connected(cells: &[Cell]) -> bool {
+    cells.windows(2).all(|pair| {
+        Direction::iter().any(|direction| {
+            pair[0].neighbor_with(direction, Topology::Cylindrical) == Some(pair[1])
+        })
+    })
+}
+
+#[test]
+fn test_cells_from_linestring() {
+    let line = linestring_2d();
+    let cells = Cell::from_linestring(&line, 9).expect("cells");
+
+    let start = Cell::from_geopoint(line.points().next().expect("point"), 9).expect("cell index");
+    let end =
+        Cell::from_geopoint(line.points().next_back().expect("point"), 9).expect("cell index");
+    assert_eq!(cells.first(), Some(&start));
+    assert_eq!(cells.last(), Some(&end));
+    assert!(is_connected(&cells));
+
+    // Same as a 4-connected grid path between cell centers
+    for (from, to) in [(start, end), (end, start)] {
+        let [lat0, lng0] = from.to_point();
+        let [lat1, lng1] = to.to_point();
+        let centers = line_string![(x: lng0, y: lat0), (x: lng1, y: lat1)];
+        assert_eq!(
+            Cell::from_linestring(&centers, 9),
+            from.grid_path_cells_with(&to, Neighborhood::VonNeumann)
+        );
+    }
+
+    // Vertices only
+    let point = line_string![(x: -3.7038, y: 40.4168)];
+    assert_eq!(
+        Cell::from_linestring(&point, 12),
+        Ok(vec![
+            Cell::from_point(40.4168, -3.7038, 12).expect("cell index")
+        ])
+    );
+    assert_eq!(
+        Cell::from_linestring(&LineString::new(vec![]), 12),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn test_cells_from_multilinestring() {
+    let lines = multi_line_string_2d();
+    let cells = Cell::from_multilinestring(&lines, 10).expect("cells");
+
+    // Closed rings, without duplicates
+    let mut unique = cells.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), cells.len());
+
+    let mut expected = Vec::new();
+    for line in lines.iter() {
+        for cell in Cell::from_linestring(line, 10).expect("cells") {
+            if !expected.contains(&cell) {
+                expected.push(cell);
+            }
+        }
+    }
+    assert_eq!(cells, expected);
+    assert_eq!(
+        Cell::from_multilinestring(&MultiLineString::new(vec![]), 10),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn test_cells_from_geodesic_linestring() {
+    // Geodesics bow poleward
+    let line = line_string![(x: -60.0, y: 60.0), (x: 60.0, y: 60.0)];
+    let rhumb = Cell::from_linestring(&line, 6).expect("cells");
+    let geodesic = Cell::from_linestring_with(&line, 6, Interpolation::Geodesic).expect("cells");
+    assert!(is_connected(&geodesic));
+    assert_eq!(rhumb.first(), geodesic.first());
+    assert_eq!(rhumb.last(), geodesic.last());
+
+    let northmost = |cells: &[Cell]| {
+        cells
+            .iter()
+            .map(|cell| cell.to_point()[0])
+            .fold(f64::MIN, f64::max)
+    };
+    assert!(northmost(&geodesic) > 72.0);
+    assert!(northmost(&rhumb) < 61.0);
+
+    // Short segments are hardly affected
+    let line = line_string![(x: 174.6, y: -41.4), (x: 175.1, y: -40.9)];
+    assert_eq!(
+        Cell::from_linestring_with(&line, 10, Interpolation::Geodesic),
+        Cell::from_linestring(&line, 10)
+    );
+
+    // Across the antimeridian
+    let line = line_string![(x: 179.5, y: -17.0), (x: -179.5, y: -17.0)];
+    let cells = Cell::from_linestring_with(&line, 10, Interpolation::Geodesic).expect("cells");
+    assert!(is_connected(&cells));
+    assert!(cells.len() < 5);
+}
+
+#[test]
+fn test_linestring_invalid_resolution() {
+    let line = linestring_2d();
+    assert_eq!(
+        Cell::from_linestring(&line, 27),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+    assert_eq!(
+        Cell::from_multilinestring_with(&multi_line_string_2d(), 27, Interpolation::Geodesic),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_cover_geometry() {
+    let mode = ContainmentMode::Center;
+    let sorted = |mut cells: Vec<Cell>| {
+        cells.sort();
+        cells.dedup();
+        Ok(cells)
+    };
+
+    // Each geometry type uses its dedicated cover
+    let point = point_2d();
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::Point(point), 10, mode),
+        Ok(vec![Cell::from_geopoint(point, 10).expect("cell index")])
+    );
+    let points = Cell::from_multipoint(multi_point_2d(), 10).collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::MultiPoint(multi_point_2d()), 10, mode),
+        sorted(points.expect("cells"))
+    );
+    let line = linestring_2d();
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::LineString(line.clone()), 8, mode),
+        sorted(Cell::from_linestring(&line, 8).expect("cells"))
+    );
+    let segment = Line::new(line.0[0], line.0[1]);
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::Line(segment), 8, mode),
+        sorted(Cell::from_linestring(&line, 8).expect("cells"))
+    );
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::Polygon(polygon_2d()), 8, mode),
+        Cell::from_polygon(&polygon_2d(), 8, mode)
+    );
+
+    // Rectangles and triangles are filled as polygons
+    let rect = Rect::new(coord! { x: -3.9, y: 40.3 }, coord! { x: -3.5, y: 40.6 });
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::Rect(rect), 12, ContainmentMode::Contains),
+        Cell::from_polygon(&rect.to_polygon(), 12, ContainmentMode::Contains)
+    );
+    let triangle = Triangle::new(line.0[0], line.0[1], coord! { x: -100.0, y: 45.0 });
+    assert_eq!(
+        Cell::cover_geometry(&Geometry::Triangle(triangle), 6, mode),
+        Cell::from_polygon(&triangle.to_polygon(), 6, mode)
+    );
+}
+
+#[test]
+fn test_cover_geometry_collection() {
+    let collection = geometry_collection_2d();
+
+    for mode in [
+        ContainmentMode::Center,
+        ContainmentMode::Contains,
+        ContainmentMode::Intersects,
+    ] {
+        let cells =
+            Cell::cover_geometry(&Geometry::GeometryCollection(collection.clone()), 7, mode)
+                .expect("cells");
+
+        let mut expected = Vec::new();
+        for geometry in collection.iter() {
+            expected.extend(Cell::cover_geometry(geometry, 7, mode).expect("cells"));
+        }
+        expected.sort();
+        expected.dedup();
+
+        assert_eq!(cells, expected);
+        assert!(cells.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    assert_eq!(
+        Cell::cover_geometry(
+            &Geometry::GeometryCollection(collection),
+            27,
+            ContainmentMode::Center
+        ),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_cover_geometry_collection_adjacent() {
+    // Two rectangles sharing an edge through the middle of a cell
+    let cell = Cell::from_point(40.4168, -3.7038, 7).expect("cell index");
+    let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+    let middle = (xmin + xmax) / 2.0;
+    let (south, north) = (ymin - 0.1, ymax + 0.1);
+    let west = Rect::new(
+        coord! { x: xmin - 0.1, y: south },
+        coord! { x: middle, y: north },
+    );
+    let east = Rect::new(
+        coord! { x: middle, y: south },
+        coord! { x: xmax + 0.1, y: north },
+    );
+    let whole = Rect::new(
+        coord! { x: xmin - 0.1, y: south },
+        coord! { x: xmax + 0.1, y: north },
+    );
+
+    let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+        Geometry::Rect(west),
+        Geometry::Polygon(east.to_polygon()),
+    ]));
+    for mode in [
+        ContainmentMode::Center,
+        ContainmentMode::Contains,
+        ContainmentMode::Intersects,
+    ] {
+        let cells = Cell::cover_geometry(&collection, 7, mode).expect("cells");
+        assert_eq!(
+            cells,
+            Cell::from_polygon(&whole.to_polygon(), 7, mode).expect("cells")
+        );
+    }
+
+    // Neither rectangle contains the cell alone
+    let cells = Cell::cover_geometry(&collection, 7, ContainmentMode::Contains).expect("cells");
+    assert!(cells.contains(&cell));
+    for part in [west, east] {
+        let cells = Cell::cover_geometry(&Geometry::Rect(part), 7, ContainmentMode::Contains)
+            .expect("cells");
+        assert!(!cells.contains(&cell));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::tiles::Tile;
+use crate::{Cell, Direction, Metric, Neighborhood, Topology};
+
+const BOUNDED: Topology = Topology::Bounded;
+const CYLINDRICAL: Topology = Topology::Cylindrical;
+
+fn tile_cell(x: u32, y: u32, z: u8) -> Cell {
+    Tile::new(x, y, z).to_cell().expect("cell index")
+}
+
+#[test]
+fn test_grid_disk() {
+    let cell = Cell::new(5209574053332910079);
+
+    assert_eq!(cell.grid_disk(0), vec![cell]);
+
+    // Disk contains the center and all its edge neighbors
+    let disk = cell.grid_disk(1);
+    assert_eq!(disk.len(), 9);
+    for neighbor in cell.neighbors().iter().flatten() {
+        assert!(disk.contains(neighbor));
+    }
+
+    // Row by row, from north-west
+    let tile = cell.to_tile();
+    assert_eq!(disk[0], tile_cell(tile.x - 1, tile.y - 1, tile.z));
+    assert_eq!(disk[8], tile_cell(tile.x + 1, tile.y + 1, tile.z));
+
+    // Root cell has nothing around
+    let root = Cell::new(5192650370358181887);
+    assert_eq!(root.grid_disk(3), vec![root]);
+}
+
+#[test]
+fn test_grid_disk_distances() {
+    let cell = tile_cell(100, 100, 8);
+    let disk = cell.grid_disk_distances(3);
+
+    assert_eq!(disk.len(), 49);
+    assert_eq!(
+        disk.iter().map(|(cell, _)| *cell).collect::<Vec<_>>(),
+        cell.grid_disk(3)
+    );
+
+    for k in 0..=3 {
+        let ring = disk.iter().filter(|(_, d)| *d == k).count() as u32;
+        assert_eq!(ring, if k == 0 { 1 } else { 8 * k });
+    }
+
+    assert!(disk.contains(&(tile_cell(97, 103, 8), 3)));
+    assert!(disk.contains(&(tile_cell(101, 98, 8), 2)));
+}
+
+#[test]
+fn test_grid_disk_edges() {
+    // Corners are clipped
+    assert_eq!(tile_cell(0, 0, 1).grid_disk(1).len(), 4);
+    assert_eq!(tile_cell(7, 7, 3).grid_disk(2).len(), 9);
+    assert_eq!(tile_cell(0, 7, 3).grid_disk_with(2, BOUNDED).len(), 9);
+
+    // Wrapping across the antimeridian
+    let disk = tile_cell(0, 4, 3).grid_disk_distances_with(1, CYLINDRICAL);
+    assert_eq!(disk.len(), 9);
+    assert!(disk.contains(&(tile_cell(7, 3, 3), 1)));
+    assert!(disk.contains(&(tile_cell(7, 5, 3), 1)));
+
+    // Columns are never repeated on narrow grids
+    let disk = tile_cell(1, 1, 2).grid_disk_distances_with(5, CYLINDRICAL);
+    assert_eq!(disk.len(), 16);
+    assert!(disk.contains(&(tile_cell(3, 1, 2), 2)));
+    assert!(disk.contains(&(tile_cell(0, 1, 2), 1)));
+    assert!(disk.contains(&(tile_cell(3, 3, 2), 2)));
+}
+
+#[test]
+fn test_grid_ring() {
+    let cell = tile_cell(10, 10, 5);
+    let ring = cell.grid_ring(1);
+
+    // Clockwise from the north-west corner
+    let truth = [
+        (9, 9),
+        (10, 9),
+        (11, 9),
+        (11, 10),
+        (11, 11),
+        (10, 11),
+        (9, 11),
+        (9, 10),
+    ];
+    assert_eq!(
+        ring,
+        truth
+            .iter()
+            .map(|(x, y)| tile_cell(*x, *y, 5))
+            .collect::<Vec<_>>()
+    );
+
+    // Rings partition the disk
+    for k in 0..=4 {
+        let ring = cell.grid_ring(k);
+        let disk = cell.grid_disk_distances(k);
+        let expected = disk.iter().filter(|(_, d)| *d == k).count();
+
+        assert_eq!(ring.len(), expected);
+        assert!(ring.iter().all(|cell| disk.contains(&(*cell, k))));
+    }
+}
+
+#[test]
+fn test_grid_ring_edges() {
+    // North-west corner of the grid
+    let ring = tile_cell(0, 0, 3).grid_ring(1);
+    assert_eq!(
+        ring,
+        vec![tile_cell(1, 0, 3), tile_cell(1, 1, 3), tile_cell(0, 1, 3)]
+    );
+
+    // Last column, with and without wrapping
+    let cell = tile_cell(7, 3, 3);
+    assert_eq!(cell.grid_ring(2).len(), 9);
+    assert_eq!(cell.grid_ring_with(2, CYLINDRICAL).len(), 16);
+    assert!(
+        cell.grid_ring_with(2, CYLINDRICAL)
+            .contains(&tile_cell(1, 2, 3))
+    );
+
+    // Beyond the grid
+    assert_eq!(cell.grid_ring(8), vec![]);
+
+    // Rings never repeat cells on narrow grids
+    for k in 0..=4 {
+        for topology in [BOUNDED, CYLINDRICAL] {
+            let ring = tile_cell(1, 1, 2).grid_ring_with(k, topology);
+            let disk = tile_cell(1, 1, 2).grid_disk_distances_with(k, topology);
+            let expected = disk.iter().filter(|(_, d)| *d == k).count();
+
+            assert_eq!(ring.len(), expected);
+            assert!(ring.iter().all(|cell| disk.contains(&(*cell, k))));
+        }
+    }
+}
+
+#[test]
+fn test_grid_distance() {
+    let cell = tile_cell(10, 10, 5);
+
+    assert_eq!(cell.grid_distance(&cell), Ok(0));
+    assert_eq!(cell.grid_distance(&tile_cell(13, 8, 5)), Ok(3));
+    assert_eq!(
+        cell.grid_distance_with(&tile_cell(13, 8, 5), Metric::Manhattan, BOUNDED),
+        Ok(5)
+    );
+
+    // Distance is consistent with disks
+    for (other, k) in cell.grid_disk_distances(4) {
+        assert_eq!(cell.grid_distance(&other), Ok(k));
+        assert_eq!(other.grid_distance(&cell), Ok(k));
+    }
+
+    // Wrapping across the antimeridian
+    let west = tile_cell(0, 3, 3);
+    let east = tile_cell(7, 5, 3);
+    let chebyshev = Metric::Chebyshev;
+    let manhattan = Metric::Manhattan;
+    assert_eq!(west.grid_distance(&east), Ok(7));
+    assert_eq!(
+        west.grid_distance_with(&east, chebyshev, CYLINDRICAL),
+        Ok(2)
+    );
+    assert_eq!(
+        west.grid_distance_with(&east, manhattan, CYLINDRICAL),
+        Ok(3)
+    );
+    assert_eq!(west.grid_distance_with(&east, manhattan, BOUNDED), Ok(9));
+}
+
+#[test]
+fn test_grid_path_cells() {
+    let start = tile_cell(10, 10, 6);
+    let ends = [
+        tile_cell(10, 10, 6),
+        tile_cell(17, 12, 6),
+        tile_cell(3, 1, 6),
+        tile_cell(10, 30, 6),
+        tile_cell(14, 6, 6),
+    ];
+
+    for end in ends.iter() {
+        let moore = start.grid_path_cells(end).expect("path");
+        let von_neumann = start
+            .grid_path_cells_with(end, Neighborhood::VonNeumann)
+            .expect("path");
+
+        for path in [&moore, &von_neumann] {
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(end));
+        }
+
+        // Path lengths match grid distances
+        let chebyshev = start.grid_distance(end).expect("distance");
+        let manhattan = start
+            .grid_distance_with(end, Metric::Manhattan, BOUNDED)
+            .expect("distance");
+        assert_eq!(moore.len() as u32, chebyshev + 1);
+        assert_eq!(von_neumann.len() as u32, manhattan + 1);
+
+        // Consecutive cells are neighbors
+        for pair in moore.windows(2) {
+            assert!(pair[0].direction_to_neighbor(&pair[1]).is_some());
+        }
+        for pair in von_neumann.windows(2) {
+            let direction = pair[0].direction_to_neighbor(&pair[1]).expect("neighbor");
+            assert!(Direction::iter().any(|d| d == direction));
+        }
+    }
+}
+
+#[test]
+fn test_grid_path_cells_straight() {
+    let start = tile_cell(2, 2, 4);
+    let end = tile_cell(5, 5, 4);
+
+    let truth = (2..=5).map(|i| tile_cell(i, i, 4)).collect::<Vec<_>>();
+    assert_eq!(start.grid_path_cells(&end), Ok(truth));
+
+    // Horizontal step first when crossing a corner
+    let truth = [(2, 2), (3, 2), (3, 3), (4, 3), (4, 4), (5, 4), (5, 5)];
+    assert_eq!(
+        start.grid_path_cells_with(&end, Neighborhood::VonNeumann),
+        Ok(truth.iter().map(|(x, y)| tile_cell(*x, *y, 4)).collect())
+    );
+}
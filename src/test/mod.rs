@@ -1,6 +1,14 @@
 mod cells;
+mod collections;
+mod coverer;
 mod data;
 mod directions;
+mod dissolve;
 mod errors;
 mod geo;
+mod grid;
+mod polyfill;
+mod ranges;
+#[cfg(feature = "serde")]
+mod serde;
 mod tiles;
@@ -0,0 +1,152 @@
+use super::data::*;
+use crate::errors::*;
+use crate::tiles::Tile;
+use crate::{Cell, ContainmentMode};
+use geo::{BoundingRect, Contains, Intersects, MultiPolygon, Point, Polygon, Relate, polygon};
+
+const CENTER: ContainmentMode = ContainmentMode::Center;
+const CONTAINS: ContainmentMode = ContainmentMode::Contains;
+const INTERSECTS: ContainmentMode = ContainmentMode::Intersects;
+
+// Check every cell of the polygon's bounding box one by one
+fn brute_force(polygon: &Polygon, res: u8, mode: ContainmentMode) -> Vec<Cell> {
+    let bounds = polygon.bounding_rect().expect("bounds");
+    let nw = Tile::from_point(bounds.max().y, bounds.min().x, res).expect("tile");
+    let se = Tile::from_point(bounds.min().y, bounds.max().x, res).expect("tile");
+
+    let mut cells = Vec::new();
+    for y in nw.y..=se.y {
+        for x in nw.x..=se.x {
+            let cell = Tile::new(x, y, res).to_cell().expect("cell index");
+            let [lat, lng] = cell.to_point();
+            let rect = crate::polyfill::cell_to_rect(&cell);
+            let inside = match mode {
+                ContainmentMode::Center => polygon.intersects(&Point::new(lng, lat)),
+                ContainmentMode::Contains => polygon.contains(&rect),
+                ContainmentMode::Intersects => {
+                    let matrix = polygon.relate(&rect);
+                    matrix.is_intersects() && !matrix.is_touches()
+                }
+            };
+            if inside {
+                cells.push(cell);
+            }
+        }
+    }
+
+    cells.sort_by_key(|cell| cell.get());
+    cells
+}
+
+fn triangle() -> Polygon {
+    polygon![
+        (x: 174.6, y: -41.4),
+        (x: 175.1, y: -41.2),
+        (x: 174.8, y: -40.9),
+    ]
+}
+
+#[test]
+fn test_polyfill_matches_brute_force() {
+    let polygons = [polygon_2d(), polygon_2d_with_interior(), triangle()];
+    let cases = [(&polygons[0], 8), (&polygons[1], 8), (&polygons[2], 12)];
+
+    for (polygon, res) in cases.iter() {
+        for mode in [CENTER, CONTAINS, INTERSECTS] {
+            let cells = Cell::from_polygon(polygon, *res, mode).expect("cells");
+            assert!(!cells.is_empty());
+            assert_eq!(cells, brute_force(polygon, *res, mode));
+        }
+    }
+}
+
+#[test]
+fn test_polyfill_modes_are_nested() {
+    let polygon = triangle();
+    let contains = Cell::from_polygon(&polygon, 13, CONTAINS).expect("cells");
+    let center = Cell::from_polygon(&polygon, 13, CENTER).expect("cells");
+    let intersects = Cell::from_polygon(&polygon, 13, INTERSECTS).expect("cells");
+
+    assert!(contains.len() < center.len());
+    assert!(center.len() < intersects.len());
+    assert!(contains.iter().all(|cell| center.contains(cell)));
+    assert!(center.iter().all(|cell| intersects.contains(cell)));
+}
+
+#[test]
+fn test_polyfill_interior_ring() {
+    let outer = Cell::from_polygon(&polygon_2d(), 9, CENTER).expect("cells");
+    let holed = Cell::from_polygon(&polygon_2d_with_interior(), 9, CENTER).expect("cells");
+    let hole = polygon![
+        (x: -121.886330, y: 37.338207),
+        (x: -121.886330, y: 36.778259),
+        (x: -119.417931, y: 36.778259),
+        (x: -119.417931, y: 37.338207),
+    ];
+
+    assert!(holed.len() < outer.len());
+    for cell in holed.iter() {
+        let [lat, lng] = cell.to_point();
+        assert!(!hole.contains(&Point::new(lng, lat)));
+    }
+}
+
+#[test]
+fn test_polyfill_cell_polygon() {
+    let cell = Cell::new(5209574053332910079);
+    let polygon = cell.to_polygon();
+
+    // Same resolution returns the cell itself, whatever the mode
+    for mode in [CENTER, CONTAINS, INTERSECTS] {
+        assert_eq!(
+            Cell::from_polygon(&polygon, cell.resolution(), mode),
+            Ok(vec![cell])
+        );
+    }
+
+    // Finer resolution returns all the descendants
+    let kids = cell
+        .children(7)
+        .expect("children")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("cell index");
+    assert_eq!(Cell::from_polygon(&polygon, 7, INTERSECTS), Ok(kids));
+
+    // Coarser resolution
+    assert_eq!(Cell::from_polygon(&polygon, 2, CONTAINS), Ok(vec![]));
+    assert_eq!(
+        Cell::from_polygon(&polygon, 2, INTERSECTS),
+        Ok(vec![cell.parent(2).expect("cell index")])
+    );
+}
+
+#[test]
+fn test_polyfill_invalid_resolution() {
+    let polygon = polygon_2d();
+    assert_eq!(
+        Cell::from_polygon(&polygon, 27, CENTER),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_polyfill_multipolygon() {
+    let polygons = MultiPolygon::new(vec![polygon_2d_with_interior(), triangle()]);
+
+    for mode in [CENTER, CONTAINS, INTERSECTS] {
+        let mut expected = Cell::from_polygon(&polygons.0[0], 9, mode).expect("cells");
+        expected.extend(Cell::from_polygon(&polygons.0[1], 9, mode).expect("cells"));
+        expected.sort();
+
+        assert_eq!(Cell::from_multipolygon(&polygons, 9, mode), Ok(expected));
+    }
+
+    assert_eq!(
+        Cell::from_multipolygon(&MultiPolygon::new(vec![]), 9, CENTER),
+        Ok(vec![])
+    );
+    assert_eq!(
+        Cell::from_multipolygon(&polygons, 27, CENTER),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
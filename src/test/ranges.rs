@@ -0,0 +1,220 @@
+use crate::Cell;
+use crate::errors::*;
+use crate::tiles::Tile;
+
+// Check every cell of the grid one by one
+fn brute_force(xmin: f64, ymin: f64, xmax: f64, ymax: f64, res: u8) -> Vec<Cell> {
+    let boxes = if xmin <= xmax {
+        vec![(xmin, xmax)]
+    } else {
+        vec![(xmin, 180.0), (-180.0, xmax)]
+    };
+
+    let n = 1_u32 << res;
+    let mut cells = Vec::new();
+    for y in 0..n {
+        for x in 0..n {
+            let cell = Tile::new(x, y, res).to_cell().expect("cell index");
+            let [cxmin, cymin, cxmax, cymax] = cell.to_bbox();
+            let inside = boxes
+                .iter()
+                .any(|&(west, east)| cxmin < east && cxmax > west && cymin < ymax && cymax > ymin);
+            if inside {
+                cells.push(cell);
+            }
+        }
+    }
+
+    cells.sort();
+    cells
+}
+
+// Expand ranges into the cells they contain
+fn expand(ranges: &[std::ops::RangeInclusive<u64>], res: u8) -> Vec<Cell> {
+    let step = 1_u64 << (52 - 2 * res as u64);
+    ranges
+        .iter()
+        .flat_map(|range| range.clone().step_by(step as usize))
+        .map(|index| Cell::try_from(index).expect("cell index"))
+        .collect()
+}
+
+#[test]
+fn test_bbox_ranges_matches_brute_force() {
+    let boxes = [
+        (-3.9, 40.3, 12.1, 52.7),
+        (-121.9, 36.7, -119.4, 37.4),
+        (174.6, -41.4, 175.1, -40.9),
+        (-179.3, -60.2, 179.3, 60.2),
+        // Crossing the antimeridian
+        (170.3, -20.1, -170.7, -10.6),
+        (150.2, -75.4, 140.1, 80.3),
+        (170.0, -10.0, -180.0, 10.0),
+        (170.0, -10.0, 180.0, 10.0),
+    ];
+
+    for (xmin, ymin, xmax, ymax) in boxes {
+        for res in [0, 1, 4, 7] {
+            let ranges = Cell::bbox_ranges(xmin, ymin, xmax, ymax, res).expect("ranges");
+            assert_eq!(
+                expand(&ranges, res),
+                brute_force(xmin, ymin, xmax, ymax, res)
+            );
+
+            // Sorted and not contiguous
+            let step = 1_u64 << (52 - 2 * res as u64);
+            assert!(ranges.windows(2).all(|w| w[0].end() + step < *w[1].start()));
+        }
+    }
+
+    // Edges just off tile edges keep the tiles they overlap
+    for res in 1..=7 {
+        let cell = Cell::from_point(10.0, 10.0, res).expect("cell index");
+        let [west, south, east, north] = cell.to_bbox();
+        let boxes = [
+            (-10.0, -10.0, 1e-4, 10.0),
+            (-1e-4, -10.0, 10.0, 10.0),
+            (-10.0, -10.0, -1e-4, 10.0),
+            (1e-4, -10.0, 10.0, 10.0),
+            (west - 1e-4, south - 1e-4, east + 1e-4, north + 1e-4),
+            (west + 1e-4, south + 1e-4, east - 1e-4, north - 1e-4),
+            (east - 1e-4, north - 1e-4, east + 1e-4, north + 1e-4),
+        ];
+
+        for (xmin, ymin, xmax, ymax) in boxes {
+            let ranges = Cell::bbox_ranges(xmin, ymin, xmax, ymax, res).expect("ranges");
+            assert_eq!(
+                expand(&ranges, res),
+                brute_force(xmin, ymin, xmax, ymax, res)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bbox_ranges_edges() {
+    // Cell bounding boxes map back to their descendants only
+    let cell = Cell::from_point(-41.2865, 174.7762, 12).expect("cell index");
+    let [xmin, ymin, xmax, ymax] = cell.to_bbox();
+    for res in 12..=16 {
+        assert_eq!(
+            Cell::bbox_ranges(xmin, ymin, xmax, ymax, res),
+            Ok(vec![cell.descendant_range(res).expect("range")])
+        );
+    }
+
+    // Degenerate boxes
+    let point = Cell::from_point(40.4168, -3.7038, 20).expect("cell index");
+    assert_eq!(
+        Cell::bbox_ranges(-3.7038, 40.4168, -3.7038, 40.4168, 20),
+        Ok(vec![point.get()..=point.get()])
+    );
+
+    // Empty boxes
+    assert_eq!(Cell::bbox_ranges(-3.9, 40.6, -3.5, 40.3, 10), Ok(vec![]));
+    assert_eq!(
+        Cell::bbox_ranges(f64::NAN, 40.3, -3.5, 40.6, 10),
+        Ok(vec![])
+    );
+
+    // Boxes ending on the antimeridian, either way
+    assert_eq!(
+        Cell::bbox_ranges(170.0, -10.0, -180.0, 10.0, 3),
+        Cell::bbox_ranges(170.0, -10.0, 180.0, 10.0, 3)
+    );
+    assert_eq!(
+        Cell::bbox_ranges(170.0, -10.0, -180.0, 10.0, 3).map(|ranges| ranges.len()),
+        Ok(2)
+    );
+
+    // Whole world
+    let root = Cell::new(5192650370358181887);
+    assert_eq!(
+        Cell::bbox_ranges(-180.0, -90.0, 180.0, 90.0, 9),
+        Ok(vec![root.descendant_range(9).expect("range")])
+    );
+    assert_eq!(
+        Cell::bbox_ranges(10.0, -90.0, 9.0, 90.0, 3),
+        Ok(vec![root.descendant_range(3).expect("range")])
+    );
+}
+
+#[test]
+fn test_bbox_ranges_with_budget() {
+    let (xmin, ymin, xmax, ymax) = (170.3, -20.1, -170.7, -10.6);
+    let exact = Cell::bbox_ranges(xmin, ymin, xmax, ymax, 10).expect("ranges");
+    assert!(exact.len() > 10);
+
+    for max_ranges in [0, 1, 2, 5, 10] {
+        let coarse =
+            Cell::bbox_ranges_with(xmin, ymin, xmax, ymax, 10, max_ranges).expect("ranges");
+        assert_eq!(coarse.len(), max_ranges.max(1));
+        assert!(coarse.windows(2).all(|w| w[0].end() < w[1].start()));
+
+        // Every exact range is still covered
+        for range in exact.iter() {
+            assert!(
+                coarse
+                    .iter()
+                    .any(|c| c.start() <= range.start() && range.end() <= c.end())
+            );
+        }
+    }
+
+    // Budget above the exact count changes nothing
+    assert_eq!(
+        Cell::bbox_ranges_with(xmin, ymin, xmax, ymax, 10, exact.len()),
+        Ok(exact)
+    );
+
+    assert_eq!(
+        Cell::bbox_ranges(xmin, ymin, xmax, ymax, 27),
+        Err(QuadbinError::InvalidResolution(27))
+    );
+}
+
+#[test]
+fn test_from_bbox_matches_ranges() {
+    let boxes = [
+        (-3.9, 40.3, 12.1, 52.7),
+        (174.6, -41.4, 175.1, -40.9),
+        (170.3, -20.1, -170.7, -10.6),
+        (150.2, -75.4, 140.1, 80.3),
+        (170.0, -10.0, -180.0, 10.0),
+        (170.0, -10.0, 180.0, 10.0),
+    ];
+
+    for (xmin, ymin, xmax, ymax) in boxes {
+        for res in [0, 3, 7] {
+            let cells = Cell::from_bbox(xmin, ymin, xmax, ymax, res)
+                .expect("cells")
+                .collect::<Vec<_>>();
+            let mut sorted = cells.clone();
+            sorted.sort();
+            assert_eq!(sorted, brute_force(xmin, ymin, xmax, ymax, res));
+
+            // Row by row, west to east
+            let tiles = cells.iter().map(|cell| cell.to_tile()).collect::<Vec<_>>();
+            assert!(tiles.windows(2).all(|w| w[0].y <= w[1].y));
+        }
+    }
+
+    // Eastern end first across the antimeridian
+    let cells = Cell::from_bbox(170.3, -20.1, -170.7, -10.6, 6)
+        .expect("cells")
+        .collect::<Vec<_>>();
+    assert_eq!(cells.first().map(|cell| cell.to_tile().x), Some(62));
+    assert_eq!(cells.last().map(|cell| cell.to_tile().x), Some(1));
+
+    // Empty and invalid boxes
+    assert_eq!(
+        Cell::from_bbox(-3.9, 40.6, -3.5, 40.3, 10)
+            .expect("cells")
+            .count(),
+        0
+    );
+    assert!(matches!(
+        Cell::from_bbox(-3.9, 40.3, -3.5, 40.6, 27),
+        Err(QuadbinError::InvalidResolution(27))
+    ));
+}
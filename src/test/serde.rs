@@ -0,0 +1,79 @@
+use crate::errors::*;
+use crate::{Cell, Direction};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    cell: Cell,
+    #[serde(with = "crate::serde_cell::hex")]
+    hex: Cell,
+    #[serde(with = "crate::serde_cell::string")]
+    string: Cell,
+}
+
+#[test]
+fn test_serde_cell() {
+    let cell = Cell::new(5209574053332910079);
+    assert_eq!(
+        serde_json::to_string(&cell).expect("json"),
+        "5209574053332910079"
+    );
+    assert_eq!(
+        serde_json::from_str::<Cell>("5209574053332910079").ok(),
+        Some(cell)
+    );
+
+    // Invalid indexes are rejected
+    let error = serde_json::from_str::<Cell>("5209574053332910078").expect_err("invalid");
+    assert_eq!(
+        error.to_string(),
+        QuadbinError::InvalidCell(Some(5209574053332910078)).to_string()
+    );
+}
+
+#[test]
+fn test_serde_cell_strings() {
+    let cell = Cell::new(5209574053332910079);
+    let record = Record {
+        cell,
+        hex: cell,
+        string: cell,
+    };
+
+    let json = serde_json::to_string(&record).expect("json");
+    assert_eq!(
+        json,
+        r#"{"cell":5209574053332910079,"hex":"484c1fffffffffff","string":"5209574053332910079"}"#
+    );
+    assert_eq!(serde_json::from_str::<Record>(&json).ok(), Some(record));
+
+    // Both string modes accept either representation
+    let json =
+        r#"{"cell":5209574053332910079,"hex":"5209574053332910079","string":"0x484c1fffffffffff"}"#;
+    assert!(serde_json::from_str::<Record>(json).is_ok());
+
+    let json =
+        r#"{"cell":5209574053332910079,"hex":"484c1ffffffffffe","string":"5209574053332910079"}"#;
+    assert!(serde_json::from_str::<Record>(json).is_err());
+}
+
+#[test]
+fn test_serde_direction() {
+    assert_eq!(serde_json::to_string(&Direction::Left).expect("json"), "2");
+    assert_eq!(
+        serde_json::from_str::<Direction>("7").ok(),
+        Some(Direction::DownLeft)
+    );
+    assert!(serde_json::from_str::<Direction>("9").is_err());
+}
+
+#[test]
+fn test_serde_error() {
+    let error = QuadbinError::ResolutionMismatch(4, 5);
+    let json = serde_json::to_string(&error).expect("json");
+    assert_eq!(json, r#"{"ResolutionMismatch":[4,5]}"#);
+    assert_eq!(
+        serde_json::from_str::<QuadbinError>(&json).ok(),
+        Some(error)
+    );
+}
@@ -1,6 +1,7 @@
 use crate::directions::Direction;
+use crate::earth::EarthModel;
 use crate::tiles::Tile;
-use crate::utils::{point_to_tile_fraction, tile_scalefactor};
+use crate::utils::point_to_tile_fraction;
 use approx::assert_relative_eq;
 
 // Declare accuracy for float values comparison on various OS
@@ -31,25 +32,70 @@ fn test_point_to_tile() {
     assert_eq!(Tile::from_point(95.0, -175.0, 2), Ok(Tile::new(0, 0, 2)));
 }
 
-// Estimate tile's area
-// TODO:
-// Investigate why it differs from Python's tests.
-// I have the impression that Python's code is erratic.
+// Compute tile's area
 #[test]
 fn test_tile_area() {
     let cases = [
-        (Tile::new(0, 0, 0), 508164597540055.75_f64),
-        (Tile::new(1, 0, 1), 127516518279497.11_f64),
-        (Tile::new(0, 1, 1), 127516518279497.11_f64),
-        (Tile::new(0, 0, 2), 3731444586048.1396_f64),
-        (Tile::new(46, 3584, 12), 3366113.9540235824_f64),
-        (Tile::new(8108, 14336, 14), 210619.87609208928_f64),
-        (Tile::new(8108, 14336, 23), 0.17313075165235314_f64),
-        (Tile::new(8108, 14336, 26), 0.0026549956100831765_f64),
+        (
+            Tile::new(0, 0, 0),
+            508164135963885.5_f64,
+            508147130188099.4_f64,
+        ),
+        (
+            Tile::new(1, 0, 1),
+            127041033990971.38_f64,
+            127036782547024.84_f64,
+        ),
+        (
+            Tile::new(0, 1, 1),
+            127041033990971.38_f64,
+            127036782547024.84_f64,
+        ),
+        (
+            Tile::new(0, 0, 2),
+            5044532457007.146_f64,
+            5084134141470.557_f64,
+        ),
+        (
+            Tile::new(46, 3584, 12),
+            3366118.1660458557_f64,
+            3394780.3490060274_f64,
+        ),
+        (
+            Tile::new(8108, 14336, 14),
+            210620.18245193025_f64,
+            212413.4797528768_f64,
+        ),
+        (
+            Tile::new(8108, 14336, 23),
+            0.17313100040109822_f64,
+            0.17467024769406922_f64,
+        ),
+        (
+            Tile::new(8108, 14336, 26),
+            0.002654999551388233_f64,
+            0.002678610317728566_f64,
+        ),
     ];
 
-    for (tile, expected) in cases.iter() {
-        assert_relative_eq!(tile.area(), *expected, epsilon = ACC);
+    for (tile, sphere, wgs84) in cases.iter() {
+        assert_relative_eq!(tile.area(EarthModel::Sphere), *sphere, max_relative = ACC);
+        assert_relative_eq!(tile.area(EarthModel::Wgs84), *wgs84, max_relative = ACC);
+    }
+}
+
+// Areas of all tiles add up to the area of the whole grid
+#[test]
+fn test_tile_area_sum() {
+    for model in [EarthModel::Sphere, EarthModel::Wgs84] {
+        let root = Tile::new(0, 0, 0).area(model);
+        let mut total = 0.0;
+        for x in 0..32 {
+            for y in 0..32 {
+                total += Tile::new(x, y, 5).area(model);
+            }
+        }
+        assert_relative_eq!(total, root, max_relative = ACC);
     }
 }
 
@@ -84,25 +130,6 @@ fn test_tile_conversion() {
     assert_relative_eq!(new_lon_offset, -44.82421875_f64, epsilon = ACC);
 }
 
-#[test]
-fn test_tile_scalefactor() {
-    assert_relative_eq!(
-        tile_scalefactor(&Tile::new(384, 368, 10)),
-        0.7075410884638627_f64,
-        epsilon = ACC
-    );
-    assert_relative_eq!(
-        tile_scalefactor(&Tile::new(384, 368, 26)),
-        0.08626970361752928_f64,
-        epsilon = ACC
-    );
-    assert_relative_eq!(
-        tile_scalefactor(&Tile::new(100, 100, 10)),
-        0.15910754230624527_f64,
-        epsilon = ACC
-    );
-}
-
 // Find tiles neighbours (aka siblings)
 #[test]
 fn test_tile_sibling() {
@@ -157,3 +184,37 @@ fn test_tile_sibling() {
         assert_eq!(tile.neighbor(all_dirs[3]), *expected);
     }
 }
+
+// Find diagonal neighbours
+#[test]
+fn test_tile_diagonal_neighbor() {
+    let tile = Tile::new(5, 5, 3);
+    let cases = [
+        (Direction::UpRight, Some(Tile::new(6, 4, 3))),
+        (Direction::UpLeft, Some(Tile::new(4, 4, 3))),
+        (Direction::DownRight, Some(Tile::new(6, 6, 3))),
+        (Direction::DownLeft, Some(Tile::new(4, 6, 3))),
+        (Direction::Center, Some(tile)),
+    ];
+
+    for (direction, expected) in cases.iter() {
+        assert_eq!(tile.neighbor(*direction), *expected);
+    }
+
+    // Grid corners
+    assert_eq!(Tile::new(0, 0, 2).neighbor(Direction::UpLeft), None);
+    assert_eq!(Tile::new(0, 0, 2).neighbor(Direction::DownLeft), None);
+    assert_eq!(Tile::new(3, 3, 2).neighbor(Direction::DownRight), None);
+    assert_eq!(Tile::new(3, 0, 2).neighbor(Direction::UpRight), None);
+    assert_eq!(
+        Tile::new(0, 0, 2).neighbor(Direction::DownRight),
+        Some(Tile::new(1, 1, 2))
+    );
+
+    // Resolution 0 has no neighbors, except itself
+    assert_eq!(Tile::new(0, 0, 0).neighbor(Direction::UpRight), None);
+    assert_eq!(
+        Tile::new(0, 0, 0).neighbor(Direction::Center),
+        Some(Tile::new(0, 0, 0))
+    );
+}
@@ -1,5 +1,7 @@
 use crate::Direction;
+use crate::Topology;
 use crate::cells::*;
+use crate::earth::EarthModel;
 use crate::errors::QuadbinError;
 use crate::utils::*;
 
@@ -29,14 +31,14 @@ impl Tile {
         point_to_tile(lat, lng, res)
     }
 
-    /// Approximate tile area in square meters.
-    pub fn area(&self) -> f64 {
-        tile_area(self)
+    /// Tile area in square meters.
+    pub fn area(&self, model: EarthModel) -> f64 {
+        tile_area(self, model)
     }
 
     /// Return tile's latitude.
     ///
-    /// See also [Tile::to_longitude].
+    /// See also [Tile::to_longitude] and [Tile::to_point].
     ///
     pub fn to_latitude(self, offset: f64) -> Option<f64> {
         tile_to_latitude(&self, offset).ok()
@@ -50,9 +52,21 @@ impl Tile {
         tile_to_longitude(&self, offset).ok()
     }
 
+    /// Return a point of the tile, as latitude and longitude.
+    ///
+    /// Offsets are measured from the tile's western and northern edges.
+    pub fn to_point(self, x_offset: f64, y_offset: f64) -> Option<[f64; 2]> {
+        tile_to_point(&self, x_offset, y_offset).ok()
+    }
+
     /// Get tile's siblings.
     pub fn neighbor(&self, direction: Direction) -> Option<Self> {
-        tile_neighbor(self, direction)
+        self.neighbor_with(direction, Topology::Bounded)
+    }
+
+    /// Get tile's siblings, using a specific [Topology].
+    pub fn neighbor_with(&self, direction: Direction, topology: Topology) -> Option<Self> {
+        tile_neighbor(self, direction, topology)
     }
 
     // /// Compute a hash from the tile.
@@ -1,6 +1,8 @@
 use crate::constants::*;
-use crate::directions::Direction;
+use crate::directions::{Direction, Neighborhood};
+use crate::earth::EarthModel;
 use crate::errors::QuadbinError;
+use crate::grid::{Metric, Topology};
 use crate::tiles::Tile;
 use std::f64::consts::PI;
 
@@ -26,6 +28,26 @@ pub(crate) fn point_to_tile_fraction(
     lng: f64,
     res: u8,
 ) -> Result<(f64, f64, u8), QuadbinError> {
+    let (x, y) = point_to_fraction(lat, lng);
+    fraction_to_tile_fraction(x, y, res)
+}
+
+/// Compute the tile in fractions for Web Mercator coordinates in meters
+/// in a specific resolution.
+pub(crate) fn mercator_to_tile_fraction(
+    x: f64,
+    y: f64,
+    res: u8,
+) -> Result<(f64, f64, u8), QuadbinError> {
+    let x = clip_number(x, -MERCATOR_EXTENT, MERCATOR_EXTENT);
+    let x = (x + MERCATOR_EXTENT) / (2.0 * MERCATOR_EXTENT);
+    let y = (MERCATOR_EXTENT - y) / (2.0 * MERCATOR_EXTENT);
+    fraction_to_tile_fraction(x, y, res)
+}
+
+/// Scale a position in the Web Mercator square to tile units, wrapping
+/// the eastern edge and clipping rows to the grid.
+fn fraction_to_tile_fraction(x: f64, y: f64, res: u8) -> Result<(f64, f64, u8), QuadbinError> {
     // Check resolution to avoid overflow
     if res > MAX_RESOLUTION {
         return Err(QuadbinError::InvalidResolution(res));
@@ -33,10 +55,10 @@ pub(crate) fn point_to_tile_fraction(
 
     // Compute tile coordinates
     let z2: f64 = (1 << res) as f64;
-    let sinlat = f64::sin(lat * PI / 180.0);
-    let x = z2 * (lng / 360.0 + 0.5);
-    let yfraction = 0.5 - 0.25 * ((1.0 + sinlat) / (1.0 - sinlat)).ln() / PI;
-    let y = clip_number(z2 * yfraction, 0.0, z2 - 1.0);
+    let x = z2 * x;
+    // Points south of the grid stay within the last row, with an offset
+    // just below 1
+    let y = clip_number(z2 * y, 0.0, z2.next_down());
 
     let x = x % z2;
     let x = if x < 0.0 { x + z2 } else { x };
@@ -45,6 +67,17 @@ pub(crate) fn point_to_tile_fraction(
     Ok((x, y, res))
 }
 
+/// Compute the position of a point in the Web Mercator square, from
+/// `(0, 0)` at the north-west corner to `(1, 1)` at the south-east one.
+///
+/// Values are neither clipped nor wrapped.
+fn point_to_fraction(lat: f64, lng: f64) -> (f64, f64) {
+    let sinlat = f64::sin(lat * PI / 180.0);
+    let x = lng / 360.0 + 0.5;
+    let y = 0.5 - 0.25 * ((1.0 + sinlat) / (1.0 - sinlat)).ln() / PI;
+    (x, y)
+}
+
 /// Compute the tile for a longitude and latitude in a specific resolution.
 pub(crate) fn point_to_tile(lat: f64, lng: f64, res: u8) -> Result<Tile, QuadbinError> {
     let (x, y, z) = point_to_tile_fraction(lat, lng, res)?;
@@ -53,6 +86,160 @@ pub(crate) fn point_to_tile(lat: f64, lng: f64, res: u8) -> Result<Tile, Quadbin
     Ok(Tile::new(x, y, z))
 }
 
+/// Compute the position of a point in tile units at a specific
+/// resolution.
+///
+/// Latitudes are clipped as in [point_to_tile_fraction], but longitudes
+/// are not wrapped, so that segments keep their direction.
+pub(crate) fn point_to_tile_units(lat: f64, lng: f64, res: u8) -> (f64, f64) {
+    let z2 = (1_u64 << res) as f64;
+    let (x, y) = point_to_fraction(clip_latitude(lat), lng);
+    (z2 * x, clip_number(z2 * y, 0.0, z2))
+}
+
+/// Visit every tile crossed by a straight segment in tile units, in order
+/// from `from` to `to`.
+///
+/// Consecutive tiles always share an edge. When the segment passes
+/// exactly through a corner, the horizontal step is taken first. Columns
+/// wrap around the antimeridian, rows are clamped to the grid.
+pub(crate) fn segment_to_tiles(
+    from: (f64, f64),
+    to: (f64, f64),
+    z: u8,
+    mut visit: impl FnMut(Tile),
+) {
+    let n = 1_i64 << z;
+    let tile = |x: i64, y: i64| Tile::new(x.rem_euclid(n) as u32, y.clamp(0, n - 1) as u32, z);
+
+    let (x0, y0) = from;
+    let (x1, y1) = to;
+    let (mut x, mut y) = (x0.floor() as i64, y0.floor() as i64);
+    let (dx, dy) = (x1 - x0, y1 - y0);
+
+    // Amanatides & Woo traversal: parameter of the next vertical and
+    // horizontal tile edges along the segment
+    let next_edge = |start: f64, cell: i64, delta: f64| {
+        if delta > 0.0 {
+            (cell as f64 + 1.0 - start) / delta
+        } else if delta < 0.0 {
+            (start - cell as f64) / -delta
+        } else {
+            f64::INFINITY
+        }
+    };
+    let (mut tx, mut ty) = (next_edge(x0, x, dx), next_edge(y0, y, dy));
+    let (step_tx, step_ty) = (1.0 / dx.abs(), 1.0 / dy.abs());
+    let (sx, sy) = (dx.signum() as i64, dy.signum() as i64);
+
+    // Step counts are fixed upfront, so rounding errors never overshoot
+    let mut nx = (x1.floor() as i64 - x).abs();
+    let mut ny = (y1.floor() as i64 - y).abs();
+
+    visit(tile(x, y));
+    while nx > 0 || ny > 0 {
+        if ny == 0 || (nx > 0 && tx <= ty) {
+            x += sx;
+            tx += step_tx;
+            nx -= 1;
+        } else {
+            y += sy;
+            ty += step_ty;
+            ny -= 1;
+        }
+        visit(tile(x, y));
+    }
+}
+
+/// Tolerance, in ULPs of the edge, when snapping fractions to tile edges.
+const EDGE_ULPS: f64 = 4.0;
+
+/// Columns and rows of the tiles intersecting a bounding box.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct BboxTiles {
+    /// Inclusive column spans, sorted and disjoint.
+    pub columns: Vec<(u32, u32)>,
+    /// Inclusive row span.
+    pub rows: (u32, u32),
+}
+
+/// Compute the tiles intersecting a bounding box in degrees.
+///
+/// Boxes with `xmin > xmax` cross the antimeridian. Tiles only touching
+/// the box along an edge are left out, unless the box is degenerate, i.e.
+/// a line or a point. Edges within a few ULPs of a tile edge are snapped
+/// to it, so the bounding box of a cell maps back to that cell.
+///
+/// Return `None` for empty boxes, i.e. `ymin > ymax` or NaN bounds.
+pub(crate) fn bbox_to_tiles(
+    xmin: f64,
+    ymin: f64,
+    xmax: f64,
+    ymax: f64,
+    res: u8,
+) -> Result<Option<BboxTiles>, QuadbinError> {
+    if res > MAX_RESOLUTION {
+        return Err(QuadbinError::InvalidResolution(res));
+    }
+    if [xmin, ymin, xmax, ymax].iter().any(|v| v.is_nan()) || ymin > ymax {
+        return Ok(None);
+    }
+
+    let z2 = (1_u64 << res) as f64;
+    let last = (1_u32 << res) - 1;
+
+    // Half-open span [start, end) of tile units, as tile indexes
+    let span = |start: f64, end: f64| -> (u32, u32) {
+        let first = (snap(start).floor() as u32).min(last);
+        let other = (snap(end).ceil() - 1.0).max(0.0) as u32;
+        (first, other.clamp(first, last))
+    };
+
+    // Tile fractions of the corners, clipped to the grid
+    let (west, north) = point_to_fraction(clip_latitude(ymax), clip_longitude(xmin));
+    let (east, south) = point_to_fraction(clip_latitude(ymin), clip_longitude(xmax));
+    let (west, east) = (z2 * west, z2 * east);
+    let (north, south) = (
+        clip_number(z2 * north, 0.0, z2),
+        clip_number(z2 * south, 0.0, z2),
+    );
+
+    let columns = if xmin <= xmax {
+        vec![span(west, east)]
+    } else {
+        // Split at the antimeridian, merging both ends if they meet
+        let (_, other) = span(0.0, east);
+        let (first, _) = span(west, z2);
+        if west >= z2 {
+            vec![(0, other)]
+        } else if east <= 0.0 {
+            vec![(first, last)]
+        } else if first <= other + 1 {
+            vec![(0, last)]
+        } else {
+            vec![(0, other), (first, last)]
+        }
+    };
+
+    let rows = span(north, south);
+
+    Ok(Some(BboxTiles { columns, rows }))
+}
+
+/// Snap a tile fraction to the closest tile edge, if within rounding
+/// errors of it.
+///
+/// The tolerance is relative to the edge, so that it stays far below the
+/// ground size of a tile at every resolution.
+fn snap(fraction: f64) -> f64 {
+    let edge = fraction.round();
+    if (fraction - edge).abs() <= edge.abs().max(1.0) * EDGE_ULPS * f64::EPSILON {
+        edge
+    } else {
+        fraction
+    }
+}
+
 /// Compute the latitude for a tile with an offset.
 pub(crate) fn tile_to_latitude(tile: &Tile, offset: f64) -> Result<f64, QuadbinError> {
     // Check if offset is between 0 and 1
@@ -85,99 +272,226 @@ pub(crate) fn tile_to_longitude(tile: &Tile, offset: f64) -> Result<f64, Quadbin
     Ok(180.0 * (2.0 * (x + offset) / z2 - 1.0))
 }
 
-/// Inverse of the scale factor at the tile center.
-pub(crate) fn tile_scalefactor(tile: &Tile) -> f64 {
-    // Get Tile coords
-    let y = tile.y as f64;
-    let z2 = (1 << tile.z) as f64;
-    let y_offset = 0.5_f64;
-
-    // Estimate scale factor
-    f64::cos(2.0 * PI * (f64::atan(f64::exp(-(2.0 * (y + y_offset) / z2 - 1.0) * PI)) / PI - 0.25))
+/// Compute the latitude and longitude for a tile with offsets.
+pub(crate) fn tile_to_point(
+    tile: &Tile,
+    x_offset: f64,
+    y_offset: f64,
+) -> Result<[f64; 2], QuadbinError> {
+    let lng = tile_to_longitude(tile, x_offset)?;
+    let lat = tile_to_latitude(tile, y_offset)?;
+    Ok([lat, lng])
 }
 
-/// Approximate area of a tile in square meters.
-// TODO:
-// This is currently a bottleneck, which requires an update.
-// See benches for area estimation assessment
-pub(crate) fn tile_area(tile: &Tile) -> f64 {
-    // Get Tile coords
-    let x = &tile.x;
-    let y = tile.y as f64;
-    let z = tile.z as usize;
-
-    // Estimate area
-    let index = std::cmp::min(AF_LEN as usize - 1, z);
-    let area_factor = AREA_FACTORS[index];
+/// Compute the Web Mercator coordinates in meters for a tile with
+/// offsets.
+pub(crate) fn tile_to_mercator(tile: &Tile, x_offset: f64, y_offset: f64) -> (f64, f64) {
+    let z2 = (1_u64 << tile.z) as f64;
+    let x = MERCATOR_EXTENT * (2.0 * (tile.x as f64 + x_offset) / z2 - 1.0);
+    let y = MERCATOR_EXTENT * (1.0 - 2.0 * (tile.y as f64 + y_offset) / z2);
+    (x, y)
+}
 
-    // !NB: Use saturation to avoid overflow for high z values
-    let shift_amount = z.saturating_mul(2);
-    let denominator = 1u64 << std::cmp::min(shift_amount, 63);
-    let mut area = area_factor * REF_AREA / denominator as f64;
+/// Exact area of a tile in square meters.
+///
+/// Tiles are bounded by meridians and parallels, so their area is the
+/// difference of two zonal areas scaled by the longitude span.
+pub(crate) fn tile_area(tile: &Tile, model: EarthModel) -> f64 {
+    let north = tile_to_latitude(tile, 0.0).expect("offset").to_radians();
+    let south = tile_to_latitude(tile, 1.0).expect("offset").to_radians();
+    let width = 2.0 * PI / (1_u64 << tile.z) as f64;
 
-    // Adjust centering
-    let center_y = if z == 0 { 0 } else { 1 << (z - 1) };
-    let center_y = center_y as f64;
+    width * (model.zonal_area(north) - model.zonal_area(south))
+}
 
-    if y < center_y - 1.0 || y > center_y {
-        let z_factor = |y_val: f64| -> f64 {
-            // Create a new tile with the same x and z but different y
-            let temp_tile = Tile::new(*x, y_val as u32, z as u8);
-            tile_scalefactor(&temp_tile).powf(2.0)
-        };
+/// Length of a tile edge in meters.
+///
+/// Northern and southern edges are measured along their parallel, eastern
+/// and western edges along their meridian.
+pub(crate) fn tile_edge_length(
+    tile: &Tile,
+    direction: Direction,
+    model: EarthModel,
+) -> Option<f64> {
+    let north = tile_to_latitude(tile, 0.0).expect("offset").to_radians();
+    let south = tile_to_latitude(tile, 1.0).expect("offset").to_radians();
+    let width = 2.0 * PI / (1_u64 << tile.z) as f64;
 
-        area *= z_factor(y) / z_factor(center_y);
+    match direction {
+        Direction::Up => Some(model.parallel_length(north, width)),
+        Direction::Down => Some(model.parallel_length(south, width)),
+        Direction::Left | Direction::Right => {
+            Some(model.meridian_arc(north) - model.meridian_arc(south))
+        }
+        _ => None,
     }
-
-    area
 }
 
 /// Compute the neighbour (sibling) tile in a specific direction.
-pub(crate) fn tile_neighbor(tile: &Tile, direction: Direction) -> Option<Tile> {
+pub(crate) fn tile_neighbor(tile: &Tile, direction: Direction, topology: Topology) -> Option<Tile> {
+    if direction == Direction::Center {
+        return Some(*tile);
+    }
+
     // Early return for a low level == no neighbors
     if tile.z == 0_u8 {
         return None;
     }
 
     // Get Tile params
-    let mut x = tile.x;
-    let mut y = tile.y;
-    let z = tile.z;
+    let (dx, dy) = direction.offset();
+    let y = tile.y.checked_add_signed(dy as i32)?;
+    let tiles_per_level = 1u32 << tile.z;
+    let x = match topology {
+        Topology::Bounded => tile.x.checked_add_signed(dx as i32)?,
+        Topology::Cylindrical => {
+            (tile.x as i64 + dx as i64).rem_euclid(tiles_per_level as i64) as u32
+        }
+    };
 
-    let tiles_per_level = 1u32 << z;
+    if x >= tiles_per_level || y >= tiles_per_level {
+        return None;
+    }
 
-    match direction {
-        Direction::Up => {
-            if y > 0 {
-                y -= 1;
-            } else {
-                return None;
-            }
-        }
-        Direction::Right => {
-            if x < tiles_per_level - 1 {
-                x += 1;
-            } else {
-                return None;
-            }
+    Some(Tile::new(x, y, tile.z))
+}
+
+/// Column offsets reachable within `k` steps from column `x`.
+fn column_offsets(x: i64, k: i64, n: i64, topology: Topology) -> (i64, i64) {
+    match topology {
+        Topology::Bounded => ((-k).max(-x), k.min(n - 1 - x)),
+        // Visit every column once, by its shortest offset
+        Topology::Cylindrical if 2 * k + 1 >= n => (-(n - 1) / 2, n / 2),
+        Topology::Cylindrical => (-k, k),
+    }
+}
+
+/// List tiles within `k` steps (Chebyshev distance) of a tile, together
+/// with their distance, row by row.
+pub(crate) fn tile_disk(tile: &Tile, k: u32, topology: Topology) -> Vec<(Tile, u32)> {
+    let n = 1_i64 << tile.z;
+    let x = tile.x as i64;
+    let y = tile.y as i64;
+    let k = k as i64;
+
+    let (dxmin, dxmax) = column_offsets(x, k, n, topology);
+    let (dymin, dymax) = ((-k).max(-y), k.min(n - 1 - y));
+
+    let mut tiles = Vec::with_capacity(((dxmax - dxmin + 1) * (dymax - dymin + 1)) as usize);
+    for dy in dymin..=dymax {
+        for dx in dxmin..=dxmax {
+            let neighbor = Tile::new((x + dx).rem_euclid(n) as u32, (y + dy) as u32, tile.z);
+            tiles.push((neighbor, dx.abs().max(dy.abs()) as u32));
         }
-        Direction::Left => {
-            if x > 0 {
-                x -= 1;
-            } else {
-                return None;
+    }
+
+    tiles
+}
+
+/// List tiles exactly `k` steps (Chebyshev distance) away from a tile,
+/// clockwise from the north-west corner.
+pub(crate) fn tile_ring(tile: &Tile, k: u32, topology: Topology) -> Vec<Tile> {
+    let n = 1_i64 << tile.z;
+    let x = tile.x as i64;
+    let y = tile.y as i64;
+    let k = k as i64;
+
+    let (dxmin, dxmax) = column_offsets(x, k, n, topology);
+    let (dymin, dymax) = ((-k).max(-y), k.min(n - 1 - y));
+    let to_tile =
+        |dx: i64, dy: i64| Tile::new((x + dx).rem_euclid(n) as u32, (y + dy) as u32, tile.z);
+
+    let mut tiles = Vec::new();
+
+    // Top row, west to east
+    if dymin == -k {
+        tiles.extend((dxmin..=dxmax).map(|dx| to_tile(dx, -k)));
+    }
+    if k == 0 {
+        return tiles;
+    }
+
+    // Right column, north to south
+    let inner = (dymin.max(1 - k), dymax.min(k - 1));
+    if dxmax == k {
+        tiles.extend((inner.0..=inner.1).map(|dy| to_tile(k, dy)));
+    }
+
+    // Bottom row, east to west
+    if dymax == k {
+        tiles.extend((dxmin..=dxmax).rev().map(|dx| to_tile(dx, k)));
+    }
+
+    // Left column, south to north
+    if dxmin == -k {
+        tiles.extend((inner.0..=inner.1).rev().map(|dy| to_tile(-k, dy)));
+    }
+
+    tiles
+}
+
+/// Count grid steps between two tiles at the same resolution.
+pub(crate) fn tile_distance(a: &Tile, b: &Tile, metric: Metric, topology: Topology) -> u32 {
+    let n = 1_u32 << a.z;
+    let dx = a.x.abs_diff(b.x);
+    let dy = a.y.abs_diff(b.y);
+
+    let dx = match topology {
+        Topology::Bounded => dx,
+        Topology::Cylindrical => dx.min(n - dx),
+    };
+
+    match metric {
+        Metric::Chebyshev => dx.max(dy),
+        Metric::Manhattan => dx + dy,
+    }
+}
+
+/// List tiles along a straight line joining two tiles' centers.
+pub(crate) fn tile_path(from: &Tile, to: &Tile, neighborhood: Neighborhood) -> Vec<Tile> {
+    let (x0, y0) = (from.x as i64, from.y as i64);
+    let (x1, y1) = (to.x as i64, to.y as i64);
+    let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+    let (nx, ny) = ((x1 - x0).abs(), (y1 - y0).abs());
+
+    let mut tiles = Vec::new();
+    let (mut x, mut y) = (x0, y0);
+    tiles.push(Tile::new(x as u32, y as u32, from.z));
+
+    match neighborhood {
+        // Bresenham's line
+        Neighborhood::Moore => {
+            let mut err = nx - ny;
+            while x != x1 || y != y1 {
+                let e2 = 2 * err;
+                if e2 >= -ny {
+                    err -= ny;
+                    x += sx;
+                }
+                if e2 <= nx {
+                    err += nx;
+                    y += sy;
+                }
+                tiles.push(Tile::new(x as u32, y as u32, from.z));
             }
         }
-        Direction::Down => {
-            if y < tiles_per_level - 1 {
-                y += 1;
-            } else {
-                return None;
+        // Supercover line, stepping to the closest next cell boundary
+        Neighborhood::VonNeumann => {
+            let (mut ix, mut iy) = (0, 0);
+            while ix < nx || iy < ny {
+                if (1 + 2 * ix) * ny <= (1 + 2 * iy) * nx {
+                    x += sx;
+                    ix += 1;
+                } else {
+                    y += sy;
+                    iy += 1;
+                }
+                tiles.push(Tile::new(x as u32, y as u32, from.z));
             }
         }
     }
 
-    Some(Tile::new(x, y, z))
+    tiles
 }
 
 // /// Compute a hash from the tile.